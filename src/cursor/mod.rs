@@ -1,5 +1,7 @@
 use std::str::Chars;
 
+use unicode_xid::UnicodeXID;
+
 mod token;
 pub use token::*;
 
@@ -32,25 +34,42 @@ impl<'a> Cursor<'a> {
             return Kind::Eof;
         };
         match first {
-            '/' => match self.advance() {
-                '/' => self.line_comment(),
-                '*' => self.block_comment(),
+            '/' => match self.first() {
+                '/' => {
+                    self.skip();
+                    self.line_comment()
+                }
+                '*' => {
+                    self.skip();
+                    self.block_comment()
+                }
                 _ => Kind::Slash,
             },
             c if c.is_whitespace() => self.whitespace(c),
+            'r' if self.is_raw_str_start() => Kind::Lit(self.raw_str()),
+            'b' if self.first() == '\'' => {
+                self.skip();
+                Kind::Lit(LitKind::Byte {
+                    terminated: self.skip_char(),
+                })
+            }
+            'b' if self.first() == '"' => {
+                self.skip();
+                Kind::Lit(LitKind::ByteStr {
+                    terminated: self.skip_str(false) != StrEnd::Unterminated,
+                })
+            }
             c if c.is_ident_start() => self.ident(),
-            c if !c.is_ascii() => self.invalid_ident(),
-            '\'' => Kind::Lit(self.char()),
+            c if is_invalid_ident_char(c) => self.invalid_ident(),
+            '\'' => Kind::Lit(LitKind::Char {
+                terminated: self.skip_char(),
+            }),
             '"' => Kind::Lit(self.str()),
             '0' => {
                 let base = match self.parse_base() {
                     Ok(base) => base,
-                    Err(JustZero) => {
-                        return Kind::Lit(LitKind::Int {
-                            base: Base::Decimal,
-                            empty: false,
-                        })
-                    }
+                    // `0`, `0.5` or `0e3`
+                    Err(JustZero) => return Kind::Lit(self.parse_maybe_float(Base::Decimal)),
                 };
                 Kind::Lit(self.parse_number(base))
             }
@@ -105,6 +124,9 @@ impl<'a> Cursor<'a> {
         // `//// comment` - not docs
         let kind = if self.first() == '/' && self.second() != '/' {
             Kind::Docs
+        } else if self.first() == '!' {
+            // `//! inner docs`
+            Kind::InnerDocs
         } else {
             Kind::LineComment
         };
@@ -116,20 +138,26 @@ impl<'a> Cursor<'a> {
     }
 
     fn block_comment(&mut self) -> Kind {
-        let mut terminated = false;
+        // Comments can be nested: `/* outer /* inner */ still comment */`
+        let mut depth = 1usize;
         while !self.is_eof() {
-            if self.first() == '*' && self.second() == '/' {
-                // Skip `*`
-                self.skip();
-                // Skip `/`
-                self.skip();
-                terminated = true;
-                break;
+            match self.two() {
+                ['/', '*'] => {
+                    self.skip_by(2);
+                    depth += 1;
+                }
+                ['*', '/'] => {
+                    self.skip_by(2);
+                    depth -= 1;
+                    if depth == 0 {
+                        return Kind::BlockComment { terminated: true };
+                    }
+                }
+                _ => self.skip(),
             }
-            self.skip();
         }
 
-        Kind::BlockComment { terminated }
+        Kind::BlockComment { terminated: false }
     }
 
     fn whitespace(&mut self, first: char) -> Kind {
@@ -148,14 +176,15 @@ impl<'a> Cursor<'a> {
 impl<'a> Cursor<'a> {
     fn ident(&mut self) -> Kind {
         self.skip_while(is_ident_continue);
-        if !self.first().is_ascii() {
+        // Like `hp😀`
+        if is_invalid_ident_char(self.first()) {
             return self.invalid_ident();
         }
         Kind::Ident
     }
 
     fn invalid_ident(&mut self) -> Kind {
-        self.skip_while(char::is_alphanumeric);
+        self.skip_while(|c| is_ident_continue(c) || is_invalid_ident_char(c));
         Kind::InvalidIdent
     }
 }
@@ -187,7 +216,7 @@ impl<'a> Cursor<'a> {
             self.skip_decimal();
         } else {
             if matches!(self.skip_hex(), HasDigits::No) {
-                return LitKind::empty_int();
+                return LitKind::empty_int(base);
             }
         }
         self.parse_maybe_float(base)
@@ -199,10 +228,27 @@ impl<'a> Cursor<'a> {
     }
 
     fn parse_maybe_float(&mut self, base: Base) -> LitKind {
+        let mut is_float = false;
         let [first, second] = self.two();
         if first == '.' && second != '.' && !second.is_ascii_alphabetic() {
             self.skip();
             self.skip_decimal();
+            is_float = true;
+        }
+
+        // Exponent: `1e9`, `2.5E-3`
+        // For hexadecimal `e` is a digit and already consumed
+        // Empty exponent like `1e` is reported by value evaluation
+        if matches!(self.first(), 'e' | 'E') {
+            self.skip();
+            if matches!(self.first(), '+' | '-') {
+                self.skip();
+            }
+            self.skip_decimal();
+            is_float = true;
+        }
+
+        if is_float {
             LitKind::Float { base }
         } else {
             LitKind::Int { base, empty: false }
@@ -245,13 +291,14 @@ impl<'a> Cursor<'a> {
 }
 
 impl<'a> Cursor<'a> {
-    fn char(&mut self) -> LitKind {
+    /// Skip char after `'`, returns `true` if char terminated
+    fn skip_char(&mut self) -> bool {
         if self.second() == '\'' && self.first() != '\\' {
             // skip char
             self.skip();
             // skip '\''
             self.skip();
-            return LitKind::Char { terminated: true };
+            return true;
         }
 
         loop {
@@ -263,7 +310,7 @@ impl<'a> Cursor<'a> {
                 // end '\''
                 '\'' => {
                     self.skip();
-                    return LitKind::Char { terminated: true };
+                    return true;
                 }
                 // newline is not supported
                 '\n' => break,
@@ -277,10 +324,33 @@ impl<'a> Cursor<'a> {
             }
         }
 
-        LitKind::Char { terminated: false }
+        false
     }
 
+    /// String after `"`, with `{` it is the head of interpolated string: `"hp: {`
     fn str(&mut self) -> LitKind {
+        match self.skip_str(true) {
+            StrEnd::Quote => LitKind::Str { terminated: true },
+            StrEnd::Unterminated => LitKind::Str { terminated: false },
+            StrEnd::Hole => LitKind::StrHead,
+        }
+    }
+
+    /// Continue interpolated string after `}` closing the hole: `} / {` or `}!"`.
+    ///
+    /// Lexer calls it instead of [Cursor::advance_token] because only it knows
+    /// which `}` closes the hole.
+    pub fn str_continue(&mut self) -> Token {
+        let start_len = self.len();
+        let end = self.skip_str(true);
+        let len = self.token_len(start_len);
+        Token::new(Kind::Lit(LitKind::StrContinue { end }), len)
+    }
+
+    /// Skip string after `"` until its end.
+    ///
+    /// With `holes` string stops after `{` starting interpolation, `{{` is escaped brace.
+    fn skip_str(&mut self, holes: bool) -> StrEnd {
         loop {
             if self.is_eof() {
                 break;
@@ -290,7 +360,14 @@ impl<'a> Cursor<'a> {
                 // end `"`
                 '"' => {
                     self.skip();
-                    return LitKind::Str { terminated: true };
+                    return StrEnd::Quote;
+                }
+                // escaped brace
+                '{' if holes && self.second() == '{' => self.skip_by(2),
+                // start of interpolation hole
+                '{' if holes => {
+                    self.skip();
+                    return StrEnd::Hole;
                 }
                 // escaped character
                 '\\' => {
@@ -302,7 +379,45 @@ impl<'a> Cursor<'a> {
             }
         }
 
-        LitKind::Str { terminated: false }
+        StrEnd::Unterminated
+    }
+
+    /// Check `"` or `#"` after `r`
+    fn is_raw_str_start(&self) -> bool {
+        let rest = self.0.as_str();
+        let hashes = rest.bytes().take_while(|&c| c == b'#').count();
+        rest.as_bytes().get(hashes) == Some(&b'"')
+    }
+
+    /// Raw string after `r`: `r"..."` or `r#"..."#`
+    fn raw_str(&mut self) -> LitKind {
+        let mut hashes = 0;
+        while self.first() == '#' {
+            self.skip();
+            hashes += 1;
+        }
+        // skip `"`
+        self.skip();
+
+        while let Some(c) = self.next() {
+            if c != '"' {
+                continue;
+            }
+            let rest = self.0.as_str();
+            let closing = rest.bytes().take_while(|&c| c == b'#').count();
+            if closing >= hashes as usize {
+                self.skip_by(hashes as usize);
+                return LitKind::RawStr {
+                    hashes,
+                    terminated: true,
+                };
+            }
+        }
+
+        LitKind::RawStr {
+            hashes,
+            terminated: false,
+        }
     }
 }
 
@@ -358,11 +473,6 @@ impl<'a> Cursor<'a> {
         }
     }
 
-    /// Like [Cursor::next] but if `None` return `'\0'`.
-    pub fn advance(&mut self) -> char {
-        self.next().unwrap_or(EOF)
-    }
-
     /// Like [Cursor::peek] but if `None` return `'\0'`.
     pub fn first(&mut self) -> char {
         self.peek().unwrap_or(EOF)
@@ -393,16 +503,21 @@ impl IsIdentStartExt for char {
     }
 }
 
+/// Identifiers follow UAX #31: `XID_Start` or `_` followed by `XID_Continue`
 #[inline]
 fn is_ident_start(c: char) -> bool {
-    // we support only ascii for identifiers
-    c.is_ascii_alphabetic() || c == '_'
+    c == '_' || c.is_xid_start()
 }
 
 #[inline]
 fn is_ident_continue(c: char) -> bool {
-    // we support only ascii for identifiers
-    c.is_ascii_alphanumeric() || c == '_'
+    c.is_xid_continue()
+}
+
+/// Non-ASCII character which can't be part of identifier, like emoji
+#[inline]
+fn is_invalid_ident_char(c: char) -> bool {
+    !c.is_ascii() && !c.is_whitespace() && !c.is_xid_continue()
 }
 
 #[test]
@@ -20,10 +20,12 @@ impl Token {
 pub enum Kind {
     // `/// Docs`
     Docs,
+    // `//! Docs`
+    InnerDocs,
     // `// comment`
     LineComment,
-    // Like `/* block comment */`
-    // Terminated when comment have end `*/`
+    // Like `/* block comment */`, can be nested
+    // Terminated when comment have end `*/` for every `/*`
     BlockComment {
         terminated: bool,
     },
@@ -110,14 +112,31 @@ pub enum LitKind {
     Float { base: Base },
     Char { terminated: bool },
     Str { terminated: bool },
+    // `"hp: {` - start of interpolated string
+    StrHead,
+    // `} / {` or `}!"` - part of interpolated string after hole
+    StrContinue { end: StrEnd },
+    // `b'x'`
+    Byte { terminated: bool },
+    // `b"bytes"`
+    ByteStr { terminated: bool },
+    // `r"raw"` or `r#"raw"#`
+    RawStr { hashes: u32, terminated: bool },
+}
+
+/// How string or its part ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrEnd {
+    /// `"`
+    Quote,
+    /// `{` starting interpolation hole
+    Hole,
+    Unterminated,
 }
 
 impl LitKind {
-    pub fn empty_int() -> Self {
-        Self::Int {
-            base: Base::Decimal,
-            empty: true,
-        }
+    pub fn empty_int(base: Base) -> Self {
+        Self::Int { base, empty: true }
     }
 }
 
@@ -0,0 +1,401 @@
+/// Stable identifier of diagnostic, like `L0003`.
+///
+/// `L` is for lexer errors, `W` for lexer warnings, `P` for parser errors.
+/// Codes are never reused, removed problems keep their numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code(pub &'static str);
+
+impl Code {
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Long explanation of this code
+    pub fn explain(&self) -> Option<&'static str> {
+        explain(self.0)
+    }
+}
+
+impl core::fmt::Display for Code {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Long explanation with examples of diagnostic `code`, case insensitive
+pub fn explain(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(code))
+        .map(|(_, explanation)| *explanation)
+}
+
+/// Every known code in order
+pub fn codes() -> impl Iterator<Item = Code> {
+    EXPLANATIONS.iter().map(|(code, _)| Code(code))
+}
+
+const EXPLANATIONS: &[(&str, &str)] = &[
+    (
+        "L0001",
+        "\
+Identifier contains a character which can not be part of identifier.
+
+Identifiers start with `_` or a letter and continue with letters, digits
+or `_`, as defined by Unicode UAX #31.
+
+    let hp€ = 10; // error
+    let hp_eur = 10; // ok
+",
+    ),
+    (
+        "L0002",
+        "\
+Literal has unknown suffix.
+
+Integers accept `i8`..`i128`, `isize`, `u8`..`u128`, `usize` and `f32`/`f64`,
+floats accept only `f32` and `f64`.
+
+    let a = 10u7; // error
+    let a = 10u8; // ok
+",
+    ),
+    (
+        "L0003",
+        "\
+String literal has no closing `\"`.
+
+    let name = \"Lore; // error
+    let name = \"Lore\"; // ok
+
+Strings can span many lines, so the error points to the opening quote.
+",
+    ),
+    (
+        "L0004",
+        "\
+Char literal has no closing `'`.
+
+    let c = 'a; // error
+    let c = 'a'; // ok
+",
+    ),
+    (
+        "L0005",
+        "\
+Block comment has no closing `*/`.
+
+Block comments can be nested, every `/*` needs its own `*/`.
+
+    /* outer /* inner */ // error
+    /* outer /* inner */ */ // ok
+",
+    ),
+    (
+        "L0006",
+        "\
+Byte literal has no closing `'`.
+
+    let b = b'a; // error
+    let b = b'a'; // ok
+",
+    ),
+    (
+        "L0007",
+        "\
+Byte string literal has no closing `\"`.
+
+    let bytes = b\"abc; // error
+    let bytes = b\"abc\"; // ok
+",
+    ),
+    (
+        "L0008",
+        "\
+Raw string has no closing `\"` followed by the same count of `#` as opening.
+
+    let raw = r#\"say \"hi\"\"; // error
+    let raw = r#\"say \"hi\"\"#; // ok
+",
+    ),
+    (
+        "L0009",
+        "\
+Integer literal with base prefix has no digits.
+
+    let mask = 0x; // error
+    let mask = 0xFF; // ok
+",
+    ),
+    (
+        "L0010",
+        "\
+Integer literal contains a digit which is too large for its base.
+
+    let flags = 0b102; // error
+    let flags = 0b101; // ok
+",
+    ),
+    (
+        "L0011",
+        "\
+Literal does not fit in its type.
+
+Integers without suffix must fit in `u128`, floats must be finite.
+Negated integers like `-128i8` may be one more than the type maximum.
+
+    let small = 256u8; // error
+    let small = 255u8; // ok
+    let byte = 128i8; // error
+    let byte = -128i8; // ok
+",
+    ),
+    (
+        "L0012",
+        "\
+Float literal has base prefix, only decimal floats are supported.
+
+    let half = 0x0.8; // error
+    let half = 0.5; // ok
+",
+    ),
+    (
+        "L0013",
+        "\
+Exponent of float literal has no digits.
+
+    let big = 1e; // error
+    let big = 1e10; // ok
+",
+    ),
+    (
+        "L0014",
+        "\
+Unknown escape sequence in char or string literal.
+
+Known escapes are `\\n`, `\\r`, `\\t`, `\\\\`, `\\0`, `\\'`, `\\\"`,
+`\\x7F` and `\\u{1F600}`.
+
+    let path = \"C:\\dir\"; // error
+    let path = \"C:\\\\dir\"; // ok
+",
+    ),
+    (
+        "L0015",
+        "\
+Char literal is empty.
+
+    let c = ''; // error
+    let c = '\\0'; // ok
+",
+    ),
+    (
+        "L0016",
+        "\
+Char literal contains more than one character.
+
+    let c = 'ab'; // error
+    let s = \"ab\"; // ok
+",
+    ),
+    (
+        "L0017",
+        "\
+Byte literal or byte string contains non-ASCII character.
+
+    let b = b'é'; // error
+    let b = b'\\xE9'; // ok
+",
+    ),
+    (
+        "L0018",
+        "\
+Interpolation hole `{` in string has no closing `}`.
+
+    let text = \"hp: {hp\"; // error
+    let text = \"hp: {hp}\"; // ok
+    let text = \"set: {{hp\"; // ok, `{{` is literal `{`
+",
+    ),
+    (
+        "L0019",
+        "\
+String contains `}` which does not close an interpolation hole.
+
+    let text = \"a } b\"; // error
+    let text = \"a }} b\"; // ok, `}}` is literal `}`
+",
+    ),
+    (
+        "W0001",
+        "\
+Identifier contains a character which looks like an ASCII one.
+
+Such identifiers are different from their ASCII look-alikes,
+which leads to confusing errors.
+
+    let hеalth = 10; // warning, `е` is Cyrillic
+    let health = 10; // ok
+",
+    ),
+    (
+        "P0001",
+        "\
+Parser expected one of listed items, but found something else.
+
+Common cases have their own codes, like `P0003` for missing `;`.
+
+    for i 0..3 { } // error, expected `in`
+    for i in 0..3 { } // ok
+",
+    ),
+    (
+        "P0002",
+        "\
+Parser found an item which can not continue the code, most often the end
+of file in the middle of declaration.
+
+    const MAX = // error
+    const MAX = 10; // ok
+",
+    ),
+    (
+        "P0003",
+        "\
+Statement is not finished with `;`.
+
+Only the last expression of block can omit it, its value is the value of block.
+
+    let hp = 10 // error
+    let hp = 10; // ok
+",
+    ),
+    (
+        "P0004",
+        "\
+Opening delimiter has no closing pair, like `(` without `)`.
+
+    let hp = (10 + 2; // error
+    let hp = (10 + 2); // ok
+",
+    ),
+    (
+        "P0005",
+        "\
+Expression is missing, like value of variable or operand of operator.
+
+    let hp = ; // error
+    let hp = 10 + ; // error
+    let hp = 10 + 2; // ok
+",
+    ),
+    (
+        "P0006",
+        "\
+Identifier is missing, like name of variable, function or parameter.
+
+    let = 10; // error
+    let hp = 10; // ok
+",
+    ),
+    (
+        "P0007",
+        "\
+Only declarations, `fn` and `const`, are allowed at the top level of file.
+
+    fn main() { } } // error, extra `}`
+    fn main() { } // ok
+",
+    ),
+    (
+        "P0008",
+        "\
+`...` is not a range operator, use `..=` for inclusive ranges.
+
+    for i in 0...3 { } // error
+    for i in 0..=3 { } // ok
+",
+    ),
+    (
+        "P0009",
+        "\
+Variable is declared with compound assignment.
+
+Only `=` can initialize variable, operators like `+=` need an existing one.
+
+    let hp += 10; // error
+    let hp = 10; // ok
+    hp += 10; // ok
+",
+    ),
+    (
+        "P0010",
+        "\
+Value of variable is another variable declaration.
+
+Declaration is not an expression and has no value.
+
+    let max = let hp = 10; // error
+    let hp = 10; let max = hp; // ok
+",
+    ),
+    (
+        "P0011",
+        "\
+Interpolation hole `{}` in string has no expression.
+
+    let text = \"hp: {}\"; // error
+    let text = \"hp: {hp}\"; // ok
+    let text = \"set: {{}}\"; // ok, `{{` and `}}` are literal braces
+",
+    ),
+];
+
+#[test]
+fn explain_codes() {
+    assert!(explain("L0003").unwrap().contains("closing `\"`"));
+    assert_eq!(explain("l0003"), explain("L0003"));
+    assert_eq!(explain("L9999"), None);
+    let mut codes: Vec<&str> = codes().map(|code| code.as_str()).collect();
+    let count = codes.len();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), count);
+}
+
+#[test]
+fn examples() {
+    use crate::{frontend::Frontend, lexer::SourceMap};
+
+    let mut sources = SourceMap::new();
+    for code in codes() {
+        let mut errors = 0;
+        for line in code.explain().unwrap().lines() {
+            let Some(example) = line.strip_prefix("    ") else {
+                continue;
+            };
+            let expected = example.contains("// error") || example.contains("// warning");
+            // Statements are checked inside of function
+            let top_level = ["fn ", "const "].iter().any(|s| example.starts_with(s));
+            let source = if top_level {
+                example.to_owned()
+            } else {
+                format!("fn main() {{\n    {example}\n}}\n")
+            };
+            let file = sources.add(code.as_str(), source);
+            let parsed = Frontend::new(&sources).parse_file(file);
+            let codes: Vec<&str> = parsed
+                .diagnostics
+                .iter()
+                .filter_map(|diagnostic| diagnostic.code.map(|code| code.as_str()))
+                .collect();
+            if expected {
+                assert!(codes.contains(&code.as_str()), "{example}: {codes:?}");
+                errors += 1;
+            } else {
+                assert!(parsed.diagnostics.is_empty(), "{example}: {codes:?}");
+            }
+        }
+        assert!(errors > 0, "{code} has no examples");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::lexer::{FileId, Span};
+
+use super::Diagnostic;
+
+/// How sure we are that [Suggestion] is what user wants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// Can be applied by tools without looking, like missing `;`
+    MachineApplicable,
+    /// Likely right, but can change meaning of code
+    MaybeIncorrect,
+}
+
+/// Replace `span` with `text`, empty `span` is insertion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub span: Span,
+    pub text: String,
+}
+
+impl Edit {
+    /// Insert `text` right after `span`
+    pub fn insert_after(span: Span, text: impl Into<String>) -> Self {
+        let end = span.as_range().end;
+        Self {
+            span: Span::new(span.file(), end, end),
+            text: text.into(),
+        }
+    }
+
+    pub fn replace(span: Span, text: impl Into<String>) -> Self {
+        Self {
+            span,
+            text: text.into(),
+        }
+    }
+}
+
+/// Fix of [Diagnostic] made of [Edit]s applied together
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub message: String,
+    pub edits: Vec<Edit>,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(message: impl Into<String>, edit: Edit, applicability: Applicability) -> Self {
+        Self {
+            message: message.into(),
+            edits: vec![edit],
+            applicability,
+        }
+    }
+}
+
+impl core::fmt::Display for Applicability {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MachineApplicable => write!(f, "machine-applicable"),
+            Self::MaybeIncorrect => write!(f, "maybe-incorrect"),
+        }
+    }
+}
+
+/// Apply every [Applicability::MachineApplicable] suggestion of `diagnostics`
+/// to `source` of `file`.
+///
+/// Suggestions overlapping already applied ones are skipped,
+/// so running this again on the result can fix more.
+pub fn apply_fixes(source: &str, file: FileId, diagnostics: &[Diagnostic]) -> String {
+    let mut suggestions: Vec<&Suggestion> = diagnostics
+        .iter()
+        .flat_map(|diagnostic| &diagnostic.suggestions)
+        .filter(|suggestion| suggestion.applicability == Applicability::MachineApplicable)
+        .filter(|suggestion| suggestion.edits.iter().all(|edit| edit.span.file() == file))
+        .collect();
+    suggestions.sort_by_key(|suggestion| suggestion.edits.iter().map(|edit| edit.span.start).min());
+
+    let mut edits: Vec<&Edit> = Vec::new();
+    for suggestion in suggestions {
+        let overlaps = suggestion.edits.iter().any(|edit| {
+            edits.iter().any(|applied| {
+                edit.span.start < applied.span.end && applied.span.start < edit.span.end
+            })
+        });
+        if !overlaps {
+            edits.extend(&suggestion.edits);
+        }
+    }
+    edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+
+    let mut fixed = String::with_capacity(source.len());
+    let mut last = 0;
+    for edit in edits {
+        fixed += &source[last..edit.span.start];
+        fixed += &edit.text;
+        last = edit.span.end;
+    }
+    fixed += &source[last..];
+    fixed
+}
+
+#[test]
+fn apply() {
+    use crate::frontend::Frontend;
+    use crate::lexer::SourceMap;
+
+    let source = "\
+fn main() {
+    let a = (1 + 2 * 3;
+    let b += a
+    a = b
+}";
+    let mut sources = SourceMap::new();
+    let file = sources.add("main.rw", source);
+    let parsed = Frontend::new(&sources).parse_file(file);
+    let fixed = apply_fixes(source, file, &parsed.diagnostics);
+    let expected = "\
+fn main() {
+    let a = (1 + 2 * 3);
+    let b += a;
+    a = b;
+}";
+    assert_eq!(fixed, expected);
+
+    // `+=` is fixed only with maybe incorrect suggestion
+    let applicabilities: Vec<Applicability> = parsed
+        .diagnostics
+        .iter()
+        .flat_map(|diagnostic| &diagnostic.suggestions)
+        .map(|suggestion| suggestion.applicability)
+        .collect();
+    assert!(applicabilities.contains(&Applicability::MaybeIncorrect));
+}
@@ -0,0 +1,206 @@
+use std::fmt::Write;
+
+use crate::lexer::{ColumnUnit, Location, SourceMap, Span};
+
+use super::{Diagnostic, Label, Suggestion};
+
+/// Writes [Diagnostic]s as JSON, one object per line.
+///
+/// Every object looks like
+/// ```json
+/// {"code":"L0003","severity":"error","message":"unterminated string",
+///  "file":"main.rw","range":[12,16],"start":{"line":2,"column":5},"end":{..},
+///  "labels":[{"primary":true,"message":"",..same location fields..}],
+///  "notes":[],"help":null,"suggestions":[{"message":"add `;`",
+///  "applicability":"machine-applicable","edits":[{"range":[16,16],"text":";"}]}]}
+/// ```
+/// `range` is byte range, `code`, location fields and `help` can be `null`.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonEmitter<'s> {
+    sources: &'s SourceMap,
+    unit: ColumnUnit,
+}
+
+impl<'s> JsonEmitter<'s> {
+    pub fn new(sources: &'s SourceMap) -> Self {
+        Self {
+            sources,
+            unit: ColumnUnit::Char,
+        }
+    }
+
+    /// Count columns in `unit`, [ColumnUnit::Char] by default
+    pub fn with_unit(mut self, unit: ColumnUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// `diagnostic` as single line JSON object without line ending
+    pub fn emit(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = String::new();
+        out.push('{');
+        out += "\"code\":";
+        match diagnostic.code {
+            Some(code) => write_str(&mut out, code.as_str()),
+            None => out += "null",
+        }
+        out += ",\"severity\":";
+        write_str(&mut out, &diagnostic.severity.to_string());
+        out += ",\"message\":";
+        write_str(&mut out, &diagnostic.message);
+        out.push(',');
+        self.write_span(&mut out, diagnostic.primary_label().map(|label| label.span));
+
+        out += ",\"labels\":[";
+        for (index, label) in diagnostic.labels.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            self.write_label(&mut out, label);
+        }
+        out += "],\"notes\":[";
+        for (index, note) in diagnostic.notes.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            write_str(&mut out, note);
+        }
+        out += "],\"help\":";
+        match &diagnostic.help {
+            Some(help) => write_str(&mut out, help),
+            None => out += "null",
+        }
+        out += ",\"suggestions\":[";
+        for (index, suggestion) in diagnostic.suggestions.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            write_suggestion(&mut out, suggestion);
+        }
+        out += "]}";
+        out
+    }
+
+    /// JSON lines of all `diagnostics`, every line ends with `\n`
+    pub fn emit_all(&self, diagnostics: &[Diagnostic]) -> String {
+        let mut out = String::new();
+        for diagnostic in diagnostics {
+            out += &self.emit(diagnostic);
+            out.push('\n');
+        }
+        out
+    }
+
+    fn write_label(&self, out: &mut String, label: &Label) {
+        let _ = write!(out, "{{\"primary\":{},\"message\":", label.primary);
+        write_str(out, &label.message);
+        out.push(',');
+        self.write_span(out, Some(label.span));
+        out.push('}');
+    }
+
+    /// `"file":..,"range":..,"start":..,"end":..` fields of `span`
+    fn write_span(&self, out: &mut String, span: Option<Span>) {
+        let Some(span) = span else {
+            *out += "\"file\":null,\"range\":null,\"start\":null,\"end\":null";
+            return;
+        };
+        let location = self.sources.locate(span, self.unit);
+        *out += "\"file\":";
+        write_str(out, location.file.path());
+        let _ = write!(out, ",\"range\":[{},{}],\"start\":", span.start, span.end);
+        write_location(out, location.start);
+        *out += ",\"end\":";
+        write_location(out, location.end);
+    }
+}
+
+fn write_suggestion(out: &mut String, suggestion: &Suggestion) {
+    *out += "{\"message\":";
+    write_str(out, &suggestion.message);
+    *out += ",\"applicability\":";
+    write_str(out, &suggestion.applicability.to_string());
+    *out += ",\"edits\":[";
+    for (index, edit) in suggestion.edits.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        let _ = write!(
+            out,
+            "{{\"range\":[{},{}],\"text\":",
+            edit.span.start, edit.span.end
+        );
+        write_str(out, &edit.text);
+        out.push('}');
+    }
+    *out += "]}";
+}
+
+fn write_location(out: &mut String, location: Location) {
+    let _ = write!(
+        out,
+        "{{\"line\":{},\"column\":{}}}",
+        location.line, location.column
+    );
+}
+
+/// `text` as JSON string with quotes
+fn write_str(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => *out += "\\\"",
+            '\\' => *out += "\\\\",
+            '\n' => *out += "\\n",
+            '\r' => *out += "\\r",
+            '\t' => *out += "\\t",
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[test]
+fn json() {
+    use super::{Applicability, Edit, Severity};
+
+    let mut sources = SourceMap::new();
+    let file = sources.add("dir\\main.rw", "let ё = \"a\nb");
+    let diagnostic = Diagnostic::from(crate::lexer::LexerError::new(
+        Span::new(file, 9, 13),
+        crate::lexer::LexerErrorKind::UnterminatedStr,
+    ))
+    .with_note("quote `\"`\tis\u{1}missing");
+    let expected = concat!(
+        r#"{"code":"L0003","severity":"error","message":"unterminated string","#,
+        r#""file":"dir\\main.rw","range":[9,13],"start":{"line":1,"column":9},"#,
+        r#""end":{"line":2,"column":2},"labels":[{"primary":true,"message":"","#,
+        r#""file":"dir\\main.rw","range":[9,13],"start":{"line":1,"column":9},"#,
+        r#""end":{"line":2,"column":2}}],"notes":["quote `\"`\tis\u0001missing"],"#,
+        r#""help":null,"suggestions":[]}"#
+    );
+    let emitter = JsonEmitter::new(&sources);
+    assert_eq!(emitter.emit(&diagnostic), expected);
+
+    let fix = Suggestion::new(
+        "add `;`",
+        Edit::insert_after(Span::new(file, 0, 3), ";"),
+        Applicability::MachineApplicable,
+    );
+    let fixed = Diagnostic::error("expected `;`").with_suggestion(fix);
+    assert!(emitter.emit(&fixed).ends_with(concat!(
+        r#""suggestions":[{"message":"add `;`","applicability":"machine-applicable","#,
+        r#""edits":[{"range":[3,3],"text":";"}]}]}"#
+    )));
+
+    let note = Diagnostic::new(Severity::Note, "done");
+    let lines = emitter.emit_all(&[diagnostic, note]);
+    assert_eq!(lines.lines().count(), 2);
+    assert!(lines.ends_with(
+        r#"{"code":null,"severity":"note","message":"done","file":null,"range":null,"start":null,"end":null,"labels":[],"notes":[],"help":null,"suggestions":[]}
+"#
+    ));
+}
@@ -0,0 +1,198 @@
+use crate::{
+    lexer::{self, LexerError, LexerErrorKind, LexerWarning},
+    parser::{ParseError, Place, TokenEdit},
+};
+
+mod codes;
+pub use codes::*;
+
+mod render;
+pub use render::*;
+
+mod json;
+pub use json::*;
+
+mod fix;
+pub use fix::*;
+
+/// Problem found in source with everything needed to show it to user
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<Code>,
+    pub message: String,
+    /// Labelled spans, rendered in source order
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+/// Span with message, primary labels are underlined with `^`, secondary with `-`
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: lexer::Span,
+    pub message: String,
+    pub primary: bool,
+}
+
+impl Label {
+    pub fn primary(span: lexer::Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            primary: true,
+        }
+    }
+
+    pub fn secondary(span: lexer::Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            primary: false,
+        }
+    }
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+            suggestions: Vec::new(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn with_code(mut self, code: Code) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+
+    /// The first primary label, or the first label if there are no primary
+    pub fn primary_label(&self) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|label| label.primary)
+            .or(self.labels.first())
+    }
+
+    /// Diagnostic of parser `error`, `spans` are spans of parsed tokens
+    pub(crate) fn from_parse_error(error: &ParseError, spans: &[lexer::Span]) -> Self {
+        let span = error.span();
+        // Empty token span points to single token
+        let span = if span.start == span.end {
+            spans[span.start.0 as usize]
+        } else {
+            span.to_lexer_span(spans)
+        };
+        let label = match error {
+            ParseError::Expected(expected) => match expected.place {
+                Place::Before => "expected before this".to_owned(),
+                Place::Here => format!("unexpected {}", expected.found),
+                Place::After => "expected after this".to_owned(),
+            },
+            ParseError::Syntax(_) | ParseError::Unexpected(_) => String::new(),
+        };
+        let diagnostic = Self::error(error.to_string())
+            .with_code(error.code())
+            .with_label(Label::primary(span, label));
+        let Some(fix) = error.fix() else {
+            return diagnostic;
+        };
+        let (message, edit) = match fix.edit {
+            TokenEdit::InsertAfter(id) => (
+                format!("add `{}`", fix.text),
+                Edit::insert_after(spans[id.as_index()], fix.text),
+            ),
+            TokenEdit::Replace(id) => (
+                format!("replace with `{}`", fix.text),
+                Edit::replace(spans[id.as_index()], fix.text),
+            ),
+        };
+        diagnostic.with_suggestion(Suggestion::new(message, edit, fix.applicability))
+    }
+}
+
+impl From<LexerError> for Diagnostic {
+    fn from(error: LexerError) -> Self {
+        let diagnostic = Self::error(error.kind.to_string()).with_code(error.kind.code());
+        match error.kind {
+            LexerErrorKind::UnterminatedHole => diagnostic
+                .with_label(Label::primary(error.span, "hole starts here"))
+                .with_help("close it with `}` or escape it as `{{`"),
+            LexerErrorKind::UnmatchedBrace => diagnostic
+                .with_label(Label::primary(error.span, ""))
+                .with_help("escape it as `}}`"),
+            LexerErrorKind::UnterminatedRawStr { hashes } => diagnostic
+                .with_label(Label::primary(error.span, ""))
+                .with_help(format!("close it with `\"{}`", "#".repeat(hashes as usize))),
+            LexerErrorKind::EmptyChar => diagnostic
+                .with_label(Label::primary(error.span, ""))
+                .with_help("use `'\\0'` for null char"),
+            LexerErrorKind::MultipleChars => diagnostic
+                .with_label(Label::primary(error.span, ""))
+                .with_help("use string literal `\"...\"` for multiple chars"),
+            _ => diagnostic.with_label(Label::primary(error.span, "")),
+        }
+    }
+}
+
+impl From<LexerWarning> for Diagnostic {
+    fn from(warning: LexerWarning) -> Self {
+        Self::warning(warning.kind.to_string())
+            .with_code(warning.kind.code())
+            .with_label(Label::primary(warning.span, ""))
+            .with_note("identifiers are compared after NFC normalisation only")
+    }
+}
+
+impl core::fmt::Display for Severity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+            Self::Note => write!(f, "note"),
+            Self::Help => write!(f, "help"),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::lexer::{ColumnUnit, SourceMap};
+
+use super::{Diagnostic, Label, Severity};
+
+const TAB_WIDTH: usize = 4;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const GREEN: &str = "\x1b[1;32m";
+const CYAN: &str = "\x1b[1;36m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// Renders [Diagnostic]s like rustc: header, location, source lines with
+/// underlined labels, then notes and help
+#[derive(Debug, Clone, Copy)]
+pub struct Renderer<'s> {
+    sources: &'s SourceMap,
+    colors: bool,
+}
+
+impl<'s> Renderer<'s> {
+    pub fn new(sources: &'s SourceMap) -> Self {
+        Self {
+            sources,
+            colors: false,
+        }
+    }
+
+    /// Use ANSI colors
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = String::new();
+        let color = self.severity_color(diagnostic.severity);
+        let header = match diagnostic.code {
+            Some(code) => format!("{}[{code}]", diagnostic.severity),
+            None => diagnostic.severity.to_string(),
+        };
+        out += &self.paint(&header, color);
+        out += &self.paint(&format!(": {}", diagnostic.message), BOLD);
+        out.push('\n');
+
+        let mut labels: Vec<&Label> = diagnostic.labels.iter().collect();
+        labels.sort_by_key(|label| (label.span.file.0, label.span.start, !label.primary));
+        let width = labels
+            .iter()
+            .map(|label| self.line_of(label).to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        if let Some(primary) = diagnostic.primary_label() {
+            self.render_path(&mut out, primary, "-->", width);
+            out += &self.gutter("", width);
+            out.push('\n');
+        }
+        // Index of file and line of the last printed source line
+        let mut last_line = None;
+        for label in labels {
+            let location = self.sources.locate(label.span, ColumnUnit::Char);
+            let line = (label.span.file, location.start.line);
+            if last_line.is_some_and(|(file, _)| file != line.0) {
+                self.render_path(&mut out, label, ":::", width);
+            }
+            let text = location.snippet.lines().next().unwrap_or("");
+            if last_line != Some(line) {
+                out += &self.gutter(&location.start.line.to_string(), width);
+                out.push(' ');
+                out += &expand_tabs(text);
+                out.push('\n');
+                last_line = Some(line);
+            }
+            self.render_underline(&mut out, label, text, width, color);
+        }
+
+        for note in &diagnostic.notes {
+            self.render_footer(&mut out, "note", note, width);
+        }
+        if let Some(help) = &diagnostic.help {
+            self.render_footer(&mut out, "help", help, width);
+        }
+        for suggestion in &diagnostic.suggestions {
+            self.render_footer(&mut out, "help", &suggestion.message, width);
+        }
+        out
+    }
+
+    fn line_of(&self, label: &Label) -> u32 {
+        let lines = self.sources.file(label.span.file).lines();
+        lines.location(label.span.start, ColumnUnit::Utf8).line
+    }
+
+    fn render_path(&self, out: &mut String, label: &Label, arrow: &str, width: usize) {
+        let location = self.sources.locate(label.span, ColumnUnit::Char);
+        let path = location.file.path();
+        *out += &" ".repeat(width);
+        *out += &self.paint(arrow, BLUE);
+        *out += &format!(" {path}:{}\n", location.start);
+    }
+
+    /// Carets under `label` on its first line `text`
+    fn render_underline(
+        &self,
+        out: &mut String,
+        label: &Label,
+        text: &str,
+        width: usize,
+        color: &str,
+    ) {
+        let file = self.sources.file(label.span.file);
+        let line_start = file.lines().location(label.span.start, ColumnUnit::Utf8);
+        // Byte offset of span start and end inside of `text`
+        let start = line_start.column as usize - 1;
+        let end = (start + label.span.end - label.span.start).min(text.len());
+        let padding = display_width(&text[..start]);
+        let len = display_width(&text[start..end]).max(1);
+
+        let (mark, color) = if label.primary {
+            ("^", color)
+        } else {
+            ("-", BLUE)
+        };
+        *out += &self.gutter("", width);
+        out.push(' ');
+        *out += &" ".repeat(padding);
+        *out += &self.paint(&mark.repeat(len), color);
+        if !label.message.is_empty() {
+            out.push(' ');
+            *out += &self.paint(&label.message, color);
+        }
+        out.push('\n');
+    }
+
+    fn render_footer(&self, out: &mut String, kind: &str, message: &str, width: usize) {
+        *out += &" ".repeat(width + 1);
+        *out += &self.paint("=", BLUE);
+        *out += &format!(" {}: {message}\n", self.paint(kind, BOLD));
+    }
+
+    /// `line |` with `line` aligned to `width`
+    fn gutter(&self, line: &str, width: usize) -> String {
+        self.paint(&format!("{line:>width$} |"), BLUE)
+    }
+
+    fn severity_color(&self, severity: Severity) -> &'static str {
+        match severity {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+            Severity::Note => GREEN,
+            Severity::Help => CYAN,
+        }
+    }
+
+    fn paint(&self, text: &str, color: &str) -> String {
+        if self.colors {
+            format!("{color}{text}{RESET}")
+        } else {
+            text.to_owned()
+        }
+    }
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', &" ".repeat(TAB_WIDTH))
+}
+
+/// Count of columns `text` takes with expanded tabs
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum()
+}
+
+#[test]
+fn render() {
+    use crate::lexer::{Lexer, LexerErrorKind, Span};
+
+    let mut sources = SourceMap::new();
+    let file = sources.add("main.rw", "fn main() {\n\tlet ё = 0b102;\n}");
+    let source = sources.file(file).source();
+    let (_, errors) = Lexer::tokenize(source);
+    assert!(matches!(
+        errors.as_slice(),
+        [error] if matches!(error.kind, LexerErrorKind::InvalidDigit { .. })
+    ));
+    let renderer = Renderer::new(&sources);
+    let expected = "\
+error[L0010]: invalid digit for base 2 literal
+ --> main.rw:2:14
+  |
+2 |     let ё = 0b102;
+  |                 ^
+";
+    assert_eq!(renderer.render(&errors[0].into()), expected);
+
+    let name = source.find('ё').unwrap();
+    let diagnostic = Diagnostic::error("unused variable")
+        .with_label(Label::secondary(Span::new(file, 0, 2), "in this function"))
+        .with_label(Label::primary(
+            Span::new(file, name, name + 2),
+            "never used",
+        ))
+        .with_note("variables are checked")
+        .with_help("remove it");
+    let expected = "\
+error: unused variable
+ --> main.rw:2:6
+  |
+1 | fn main() {
+  | -- in this function
+2 |     let ё = 0b102;
+  |         ^ never used
+  = note: variables are checked
+  = help: remove it
+";
+    assert_eq!(renderer.render(&diagnostic), expected);
+
+    let colored = renderer.with_colors(true).render(&diagnostic);
+    assert!(colored.starts_with("\x1b[1;31merror\x1b[0m"));
+}
@@ -0,0 +1,281 @@
+use std::{io, path::Path};
+
+use crate::{
+    diagnostic::{Diagnostic, JsonEmitter, Renderer, Severity},
+    lexer::{self, FileId, Kind, Lexer, LexerError, SourceMap},
+    parser::{Parser, Program, ProgramExt},
+};
+
+/// How [check_file] writes diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Rendered like rustc, look [Renderer]
+    Text { colors: bool },
+    /// JSON lines, look [JsonEmitter]
+    Json,
+}
+
+/// Backstop for files with many independent errors.
+///
+/// Cascades are dropped before the cap: errors inside of an earlier lexer
+/// error, like `}` swallowed by unterminated string, and errors inside of
+/// statement skipped by parser recovery after its root error.
+pub const DEFAULT_MAX_ERRORS: usize = 20;
+
+/// Front-end of one file: lexer and parser with all their problems
+/// merged into one list of [Diagnostic]s with byte spans
+#[derive(Debug, Clone, Copy)]
+pub struct Frontend<'s> {
+    sources: &'s SourceMap,
+    max_errors: usize,
+}
+
+/// Output of [Frontend::parse_file]
+#[derive(Debug, Clone)]
+pub struct ParsedFile {
+    pub file: FileId,
+    pub kinds: Vec<Kind>,
+    /// Spans of `kinds`, the last is [Kind::Eof]
+    pub spans: Vec<lexer::Span>,
+    pub program: Option<Program>,
+    /// Ordered by position, errors and warnings mixed
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ParsedFile {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+}
+
+impl<'s> Frontend<'s> {
+    pub fn new(sources: &'s SourceMap) -> Self {
+        Self {
+            sources,
+            max_errors: DEFAULT_MAX_ERRORS,
+        }
+    }
+
+    /// Stop reporting errors after `max_errors`, the rest are counted in a note
+    pub fn with_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = max_errors;
+        self
+    }
+
+    pub fn parse_file(&self, file: FileId) -> ParsedFile {
+        let mut lexer = Lexer::with_file(file, self.sources.file(file).source());
+        let mut kinds = Vec::new();
+        let mut spans = Vec::new();
+        loop {
+            let token = lexer.advance_token();
+            kinds.push(token.kind);
+            spans.push(token.span);
+            if let Kind::Eof = token.kind {
+                break;
+            }
+        }
+
+        let mut parser = Parser::new(&kinds);
+        let program = parser.parse_program().ok();
+
+        let mut diagnostics: Vec<Diagnostic> = lexer
+            .errors
+            .iter()
+            .copied()
+            .map(Diagnostic::from)
+            .chain(lexer.warnings.iter().copied().map(Diagnostic::from))
+            .collect();
+        // Parser errors at broken tokens are already explained by lexer
+        diagnostics.extend(
+            parser
+                .errors
+                .iter()
+                .map(|error| Diagnostic::from_parse_error(error, &spans))
+                .filter(|diagnostic| !caused_by(diagnostic, &lexer.errors)),
+        );
+        // Everything reported inside of these after the first error is its cascade
+        let cascades: Vec<lexer::Span> = lexer
+            .errors
+            .iter()
+            .map(|error| error.span)
+            .chain(
+                parser
+                    .recovered
+                    .iter()
+                    .map(|span| span.to_lexer_span(&spans)),
+            )
+            .collect();
+        let diagnostics = self.merge(diagnostics, &cascades);
+
+        ParsedFile {
+            file,
+            program,
+            diagnostics,
+            kinds,
+            spans,
+        }
+    }
+
+    /// Sort `diagnostics` by position, drop duplicates, cascades inside of
+    /// `cascades` spans and errors over the cap
+    fn merge(&self, mut diagnostics: Vec<Diagnostic>, cascades: &[lexer::Span]) -> Vec<Diagnostic> {
+        // Stable sort keeps lexer diagnostics before parser ones at the same place
+        diagnostics.sort_by_key(|diagnostic| {
+            let start = diagnostic.primary_label().map(|label| label.span.start);
+            (start, diagnostic.severity)
+        });
+        let mut merged: Vec<Diagnostic> = Vec::with_capacity(diagnostics.len());
+        let mut errors = 0;
+        let mut suppressed = 0;
+        for diagnostic in diagnostics {
+            let span = diagnostic.primary_label().map(|label| label.span);
+            // Only the first error at one place is the root
+            let duplicate = merged.iter().rev().any(|previous| {
+                previous.severity == diagnostic.severity
+                    && previous.primary_label().map(|label| label.span) == span
+                    && (diagnostic.severity == Severity::Error
+                        || previous.message == diagnostic.message)
+            });
+            if duplicate {
+                continue;
+            }
+            if diagnostic.severity == Severity::Error
+                && span.is_some_and(|span| is_cascade(span, &merged, cascades))
+            {
+                continue;
+            }
+            if diagnostic.severity == Severity::Error {
+                if errors == self.max_errors {
+                    suppressed += 1;
+                    continue;
+                }
+                errors += 1;
+            }
+            merged.push(diagnostic);
+        }
+        if suppressed > 0 {
+            let message = match suppressed {
+                1 => "1 more error was suppressed".to_owned(),
+                _ => format!("{suppressed} more errors were suppressed"),
+            };
+            merged.push(Diagnostic::new(Severity::Note, message));
+        }
+        merged
+    }
+}
+
+/// Parse file at `path` and write its diagnostics to `out` in `format`.
+///
+/// Returns `true` if file has errors.
+pub fn check_file(
+    path: impl AsRef<Path>,
+    format: Format,
+    out: &mut impl io::Write,
+) -> io::Result<bool> {
+    let mut sources = SourceMap::new();
+    let file = sources.load(path)?;
+    let parsed = Frontend::new(&sources).parse_file(file);
+    match format {
+        Format::Text { colors } => {
+            let renderer = Renderer::new(&sources).with_colors(colors);
+            for diagnostic in &parsed.diagnostics {
+                writeln!(out, "{}", renderer.render(diagnostic))?;
+            }
+        }
+        Format::Json => {
+            let emitter = JsonEmitter::new(&sources);
+            for diagnostic in &parsed.diagnostics {
+                writeln!(out, "{}", emitter.emit(diagnostic))?;
+            }
+        }
+    }
+    Ok(parsed.has_errors())
+}
+
+/// `diagnostic` points into or right after one of lexer `errors`
+fn caused_by(diagnostic: &Diagnostic, errors: &[LexerError]) -> bool {
+    let Some(label) = diagnostic.primary_label() else {
+        return false;
+    };
+    errors
+        .iter()
+        .any(|error| label.span.start <= error.span.end && error.span.start <= label.span.end)
+}
+
+/// Error at `span` is inside of one of `cascades` with already reported error
+fn is_cascade(span: lexer::Span, reported: &[Diagnostic], cascades: &[lexer::Span]) -> bool {
+    let contains = |outer: &lexer::Span, inner: lexer::Span| {
+        outer.file == inner.file && outer.start <= inner.start && inner.end <= outer.end
+    };
+    cascades
+        .iter()
+        .filter(|cascade| contains(cascade, span))
+        .any(|cascade| {
+            reported.iter().any(|previous| {
+                previous.severity == Severity::Error
+                    && previous
+                        .primary_label()
+                        .is_some_and(|label| contains(cascade, label.span))
+            })
+        })
+}
+
+#[test]
+fn frontend() {
+    let mut sources = SourceMap::new();
+    let main = sources.load("main.rw").unwrap();
+    let parsed = Frontend::new(&sources).parse_file(main);
+    assert!(parsed.program.is_some());
+    assert!(!parsed.has_errors());
+
+    // Parser errors at unterminated string are not repeated
+    let broken = sources.add(
+        "broken.rw",
+        "fn main() {\n    let a = 0b12;\n    let b = \"hp",
+    );
+    let parsed = Frontend::new(&sources).parse_file(broken);
+    let messages: Vec<&str> = parsed
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        ["invalid digit for base 2 literal", "unterminated string"]
+    );
+
+    // `}` swallowed by unterminated string is not unmatched
+    let swallowed = sources.add("swallowed.rw", "fn main() {\n    let b = \"hp;\n}\n");
+    let parsed = Frontend::new(&sources).parse_file(swallowed);
+    let messages: Vec<&str> = parsed
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message.as_str())
+        .collect();
+    assert_eq!(messages, ["unterminated string"]);
+
+    // Lexer error inside of skipped statement follows its root error
+    let skipped = sources.add("skipped.rw", "fn main() {\n    let a = ) 0b12;\n}\n");
+    let parsed = Frontend::new(&sources).parse_file(skipped);
+    assert_eq!(parsed.diagnostics.len(), 1, "{:?}", parsed.diagnostics);
+
+    let many = sources.add("many.rw", "fn main() { let a = 0b2 + 0b3 + 0b4; }");
+    let parsed = Frontend::new(&sources).with_max_errors(2).parse_file(many);
+    let severities: Vec<Severity> = parsed
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.severity)
+        .collect();
+    assert_eq!(
+        severities,
+        [Severity::Error, Severity::Error, Severity::Note]
+    );
+    assert_eq!(parsed.diagnostics[2].message, "1 more error was suppressed");
+    let parsed = Frontend::new(&sources).with_max_errors(1).parse_file(many);
+    assert_eq!(
+        parsed.diagnostics.last().unwrap().message,
+        "2 more errors were suppressed"
+    );
+}
@@ -0,0 +1,169 @@
+use super::Location;
+
+/// Line starts of source, built once per file for fast conversion
+/// between byte offsets and [Location]s.
+///
+/// Lookups are `O(log n)`, columns can be counted in UTF-8 bytes,
+/// UTF-16 code units or chars, look [ColumnUnit].
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    /// Byte offset of every line start, the first is always `0`
+    line_starts: Vec<usize>,
+    /// Every non-ASCII char of source
+    wide_chars: Vec<WideChar>,
+    len: usize,
+}
+
+/// How columns of [Location] are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnUnit {
+    /// Bytes, like [super::Span]
+    Utf8,
+    /// UTF-16 code units, like in LSP
+    Utf16,
+    /// Unicode scalar values
+    Char,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WideChar {
+    offset: usize,
+    len_utf8: u8,
+    /// Count of extra bytes compared to UTF-16 of this and all previous chars
+    extra_utf16: usize,
+    /// Count of extra bytes compared to chars of this and all previous chars
+    extra_char: usize,
+}
+
+impl WideChar {
+    fn extra(&self, unit: ColumnUnit) -> usize {
+        match unit {
+            ColumnUnit::Utf8 => 0,
+            ColumnUnit::Utf16 => self.extra_utf16,
+            ColumnUnit::Char => self.extra_char,
+        }
+    }
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut wide_chars = Vec::new();
+        let mut extra_utf16 = 0;
+        let mut extra_char = 0;
+        for (offset, c) in source.char_indices() {
+            if c == '\n' {
+                line_starts.push(offset + 1);
+            }
+            if !c.is_ascii() {
+                extra_utf16 += c.len_utf8() - c.len_utf16();
+                extra_char += c.len_utf8() - 1;
+                wide_chars.push(WideChar {
+                    offset,
+                    len_utf8: c.len_utf8() as u8,
+                    extra_utf16,
+                    extra_char,
+                });
+            }
+        }
+        Self {
+            line_starts,
+            wide_chars,
+            len: source.len(),
+        }
+    }
+
+    pub fn line_count(&self) -> u32 {
+        self.line_starts.len() as u32
+    }
+
+    /// Byte offset of the start of `line`, counted from `1`
+    pub fn line_start(&self, line: u32) -> Option<usize> {
+        let index = (line as usize).checked_sub(1)?;
+        self.line_starts.get(index).copied()
+    }
+
+    /// Byte offset of the end of `line` without `\n`
+    pub fn line_end(&self, line: u32) -> Option<usize> {
+        self.line_start(line)?;
+        Some(match self.line_start(line + 1) {
+            Some(next) => next - 1,
+            None => self.len,
+        })
+    }
+
+    /// # Panics
+    /// Panics if `offset` is out of source
+    #[track_caller]
+    pub fn location(&self, offset: usize, unit: ColumnUnit) -> Location {
+        if offset > self.len {
+            panic!("LineIndex::location(offset): offset is out of source!")
+        }
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let start = self.line_starts[line - 1];
+        let extra = self.extra_before(offset, unit) - self.extra_before(start, unit);
+        let column = offset - start - extra;
+        Location::new(line as u32, column as u32 + 1)
+    }
+
+    /// Byte offset of `location`, `None` if it is out of line
+    pub fn offset(&self, location: Location, unit: ColumnUnit) -> Option<usize> {
+        let start = self.line_start(location.line)?;
+        let end = self.line_end(location.line)?;
+        let column = (location.column as usize).checked_sub(1)?;
+        let base = self.extra_before(start, unit);
+        // Wide chars of line which end before `column`
+        let first = self.wide_chars.partition_point(|c| c.offset < start);
+        let last = self.wide_chars.partition_point(|c| c.offset < end);
+        let line_chars = &self.wide_chars[first..last];
+        let count = line_chars.partition_point(|c| {
+            // `extra` includes the char itself
+            let extra = c.extra(unit) - base;
+            c.offset + c.len_utf8 as usize - start - extra <= column
+        });
+        let extra = match count {
+            0 => 0,
+            count => line_chars[count - 1].extra(unit) - base,
+        };
+        let offset = start + column + extra;
+        (offset <= end).then_some(offset)
+    }
+
+    /// Extra bytes of wide chars before `offset`
+    fn extra_before(&self, offset: usize, unit: ColumnUnit) -> usize {
+        match self.wide_chars.partition_point(|c| c.offset < offset) {
+            0 => 0,
+            count => self.wide_chars[count - 1].extra(unit),
+        }
+    }
+}
+
+#[test]
+fn line_index() {
+    let source = "let a = 1;\nlet ё = \"😀\";\r\n\nb";
+    let index = LineIndex::new(source);
+    assert_eq!(index.line_count(), 4);
+
+    let emoji = source.find('😀').unwrap();
+    let end = source.rfind('"').unwrap();
+    let cases = [
+        (0, ColumnUnit::Utf8, (1, 1)),
+        (4, ColumnUnit::Char, (1, 5)),
+        (11, ColumnUnit::Utf8, (2, 1)),
+        (emoji, ColumnUnit::Utf8, (2, 11)),
+        (emoji, ColumnUnit::Utf16, (2, 10)),
+        (emoji, ColumnUnit::Char, (2, 10)),
+        (end, ColumnUnit::Utf8, (2, 15)),
+        (end, ColumnUnit::Utf16, (2, 12)),
+        (end, ColumnUnit::Char, (2, 11)),
+        (source.len() - 1, ColumnUnit::Char, (4, 1)),
+        (source.len(), ColumnUnit::Utf16, (4, 2)),
+    ];
+    for (offset, unit, (line, column)) in cases {
+        let location = index.location(offset, unit);
+        assert_eq!((location.line, location.column), (line, column));
+        assert_eq!(index.offset(location, unit), Some(offset));
+    }
+    assert_eq!(index.offset(Location::new(1, 100), ColumnUnit::Utf8), None);
+    assert_eq!(index.offset(Location::new(5, 1), ColumnUnit::Utf8), None);
+}
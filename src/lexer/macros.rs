@@ -39,17 +39,17 @@ macro_rules! Kind {
     [&&]    => { $crate::lexer::Kind::Binary($crate::lexer::BinaryKind![&&]) };
     [||]    => { $crate::lexer::Kind::Binary($crate::lexer::BinaryKind![||]) };
     // Assign
-    [=]     => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![=]) };
-    [+=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![+]) };
-    [-=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![-]) };
-    [*=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![*]) };
-    [/=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![/]) };
-    [%=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![%]) };
-    [^=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![^]) };
-    [|=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![|]) };
-    [&=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![&]) };
-    [<<=]   => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![<<]) };
-    [>>=]   => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![>>]) };
+    [=]     => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![=], None) };
+    [+=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![+], None) };
+    [-=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![-], None) };
+    [*=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![*], None) };
+    [/=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![/], None) };
+    [%=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![%], None) };
+    [^=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![^], None) };
+    [|=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![|], None) };
+    [&=]    => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![&], None) };
+    [<<=]   => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![<<], None) };
+    [>>=]   => { $crate::lexer::Kind::Assign($crate::lexer::AssignKind![>>], None) };
     // Delimiters
     ['(']   => { $crate::lexer::Kind::OpenDelim($crate::lexer::Delimiter::Paren) };
     ['{']   => { $crate::lexer::Kind::OpenDelim($crate::lexer::Delimiter::Brace) };
@@ -68,6 +68,9 @@ macro_rules! Kind {
     [for]   => { $crate::lexer::Kind::Keyword($crate::lexer::Keyword::For) };
     [in]    => { $crate::lexer::Kind::Keyword($crate::lexer::Keyword::In) };
     [fn]    => { $crate::lexer::Kind::Keyword($crate::lexer::Keyword::Fn) };
+    [break]    => { $crate::lexer::Kind::Keyword($crate::lexer::Keyword::Break) };
+    [continue] => { $crate::lexer::Kind::Keyword($crate::lexer::Keyword::Continue) };
+    [return]   => { $crate::lexer::Kind::Keyword($crate::lexer::Keyword::Return) };
 }
 
 macro_rules! BinaryKind {
@@ -1,4 +1,7 @@
-use crate::cursor::{self, Cursor};
+use crate::{
+    cursor::{self, Base, Cursor, StrEnd},
+    diagnostic::Code,
+};
 
 mod macros;
 pub(crate) use macros::*;
@@ -12,12 +15,42 @@ pub use span::*;
 mod location;
 pub use location::*;
 
+mod line_index;
+pub use line_index::*;
+
+mod source_map;
+pub use source_map::*;
+
+mod value;
+pub use value::*;
+
+mod ident;
+pub use ident::*;
+
 #[derive(Debug, Clone)]
 pub struct Lexer<'a> {
     pub(crate) source: &'a str,
     pub(crate) errors: Vec<LexerError>,
+    pub(crate) warnings: Vec<LexerWarning>,
     pub(crate) cursor: Cursor<'a>,
     pub(crate) span: Span,
+    /// Open interpolation holes, the innermost is last
+    pub(crate) holes: Vec<Hole>,
+    /// Emit [Kind::Trivia] tokens instead of skipping them
+    pub(crate) lossless: bool,
+    /// The last token except trivia
+    pub(crate) last: Kind,
+    /// `last` is `-` negating the next operand, like in `-128i8`
+    pub(crate) negation: bool,
+}
+
+/// Interpolation hole `{expr}` inside string
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Hole {
+    /// Position of opening `{`
+    start: usize,
+    /// Count of unclosed `{` inside hole, like in `{ {a} }`
+    depth: u32,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -39,15 +72,164 @@ pub enum LexerErrorKind {
     EmptyInt,
     UnterminatedStr,
     UnterminatedChar,
+    UnterminatedBlockComment,
+    UnterminatedByte,
+    UnterminatedByteStr,
+    /// Raw string without `"` followed by `hashes` count of `#`
+    UnterminatedRawStr {
+        hashes: u32,
+    },
+    /// Like `2` in `0b102`
+    InvalidDigit {
+        base: Base,
+    },
+    /// Integer does not fit in `u128` or its suffix type, or float is infinite
+    LiteralOverflow,
+    /// Like `0x1.5`
+    NonDecimalFloat,
+    /// Like `1e` or `2.5E-`
+    EmptyExponent,
+    /// Like `\q` or `\u{110000}`
+    InvalidEscape,
+    /// `''`
+    EmptyChar,
+    /// Like `'ab'`
+    MultipleChars,
+    /// Like `b'é'`
+    NonAsciiByte,
+    /// `{` of interpolated string without closing `}`
+    UnterminatedHole,
+    /// Like `}` in `"a } b"`, it must be escaped as `}}`
+    UnmatchedBrace,
+}
+
+impl LexerErrorKind {
+    pub const fn code(&self) -> Code {
+        Code(match self {
+            Self::InvalidIdent => "L0001",
+            Self::InvalidSuffix => "L0002",
+            Self::UnterminatedStr => "L0003",
+            Self::UnterminatedChar => "L0004",
+            Self::UnterminatedBlockComment => "L0005",
+            Self::UnterminatedByte => "L0006",
+            Self::UnterminatedByteStr => "L0007",
+            Self::UnterminatedRawStr { .. } => "L0008",
+            Self::EmptyInt => "L0009",
+            Self::InvalidDigit { .. } => "L0010",
+            Self::LiteralOverflow => "L0011",
+            Self::NonDecimalFloat => "L0012",
+            Self::EmptyExponent => "L0013",
+            Self::InvalidEscape => "L0014",
+            Self::EmptyChar => "L0015",
+            Self::MultipleChars => "L0016",
+            Self::NonAsciiByte => "L0017",
+            Self::UnterminatedHole => "L0018",
+            Self::UnmatchedBrace => "L0019",
+        })
+    }
+}
+
+impl core::error::Error for LexerError {}
+
+impl core::fmt::Display for LexerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl core::fmt::Display for LexerErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidIdent => write!(f, "invalid identifier"),
+            Self::InvalidSuffix => write!(f, "invalid literal suffix"),
+            Self::EmptyInt => write!(f, "integer literal has no digits"),
+            Self::UnterminatedStr => write!(f, "unterminated string"),
+            Self::UnterminatedChar => write!(f, "unterminated char"),
+            Self::UnterminatedBlockComment => write!(f, "unterminated block comment"),
+            Self::UnterminatedByte => write!(f, "unterminated byte"),
+            Self::UnterminatedByteStr => write!(f, "unterminated byte string"),
+            Self::UnterminatedRawStr { .. } => write!(f, "unterminated raw string"),
+            Self::InvalidDigit { base } => {
+                write!(f, "invalid digit for base {} literal", *base as u32)
+            }
+            Self::LiteralOverflow => write!(f, "literal out of range"),
+            Self::NonDecimalFloat => write!(f, "float literal must be decimal"),
+            Self::EmptyExponent => write!(f, "exponent has no digits"),
+            Self::InvalidEscape => write!(f, "invalid escape"),
+            Self::EmptyChar => write!(f, "empty char literal"),
+            Self::MultipleChars => write!(f, "char literal must contain one character"),
+            Self::NonAsciiByte => write!(f, "non-ASCII character in byte literal"),
+            Self::UnterminatedHole => write!(f, "unterminated interpolation hole"),
+            Self::UnmatchedBrace => write!(f, "unmatched `}}` in string"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LexerWarning {
+    pub span: Span,
+    pub kind: LexerWarningKind,
+}
+
+impl LexerWarning {
+    pub fn new(span: Span, kind: LexerWarningKind) -> Self {
+        Self { span, kind }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LexerWarningKind {
+    /// Like Cyrillic `а` in `heаlth`, which looks like Latin `a`
+    Confusable { found: char, similar: char },
+}
+
+impl LexerWarningKind {
+    pub const fn code(&self) -> Code {
+        Code(match self {
+            Self::Confusable { .. } => "W0001",
+        })
+    }
+}
+
+impl core::fmt::Display for LexerWarningKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Confusable { found, similar } => write!(
+                f,
+                "identifier contains `{found}` ({:#06X}) which looks like `{similar}`",
+                *found as u32
+            ),
+        }
+    }
 }
 
 impl<'a> Lexer<'a> {
+    /// Lexer of single file, spans have default [FileId]
     pub fn new(source: &'a str) -> Self {
+        Self::with_file(FileId::default(), source)
+    }
+
+    /// Lexer of `file` from [SourceMap]
+    pub fn with_file(file: FileId, source: &'a str) -> Self {
         Self {
             source,
             errors: Vec::new(),
+            warnings: Vec::new(),
             cursor: Cursor::new(source),
-            span: Span::zero(),
+            span: Span::zero(file),
+            holes: Vec::new(),
+            lossless: false,
+            last: Kind::Eof,
+            negation: false,
+        }
+    }
+
+    /// Lexer which keeps whitespace and comments as [Kind::Trivia] tokens,
+    /// so concatenated tokens reproduce the source
+    pub fn lossless(source: &'a str) -> Self {
+        Self {
+            lossless: true,
+            ..Self::new(source)
         }
     }
 
@@ -64,6 +246,21 @@ impl<'a> Lexer<'a> {
         }
         (tokens, lexer.errors)
     }
+
+    /// Tokens of [Lexer::lossless], their lexemes joined are `source`
+    pub fn tokenize_lossless(source: &'a str) -> (Vec<Token>, Vec<LexerError>) {
+        let mut lexer = Self::lossless(source);
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.advance_token();
+            if let Kind::Eof = token.kind {
+                break;
+            } else {
+                tokens.push(token);
+            }
+        }
+        (tokens, lexer.errors)
+    }
 }
 
 impl<'a> Lexer<'a> {
@@ -92,7 +289,7 @@ impl<'a> Lexer<'a> {
             ) => {{
                 if let cursor::Kind![=] = self.first().kind {
                     self.skip();
-                    Kind::Assign($assign)
+                    Kind::Assign($assign, None)
                 } else {
                     Kind::Binary($binary)
                 }
@@ -104,13 +301,56 @@ impl<'a> Lexer<'a> {
                 )
             };
         }
+
+        // `+%=`, `<<#=`, `>>?=` and the like are single tokens
+        macro_rules! overflow_assign_or_binary {
+            [$($tt:tt)*] => {{
+                let [first, second] = self.two();
+                let mode = match first.kind {
+                    cursor::Kind![%] => Some(OverflowMode::Wrapping),
+                    cursor::Kind![#] => Some(OverflowMode::Saturating),
+                    cursor::Kind![?] => Some(OverflowMode::Checked),
+                    _ => None,
+                };
+                match (mode, second.kind) {
+                    (Some(mode), cursor::Kind![=]) => {
+                        self.skip();
+                        self.skip();
+                        Kind::Assign(AssignKind![$($tt)*], Some(mode))
+                    }
+                    _ => assign_or_binary![$($tt)*],
+                }
+            }};
+        }
         
         let kind = loop {
             let token = self.next();
             if let cursor::Kind::Eof = token.kind {
+                for hole in core::mem::take(&mut self.holes) {
+                    let span = Span::new(self.span.file, hole.start, hole.start + 1);
+                    let error = LexerError::new(span, LexerErrorKind::UnterminatedHole);
+                    self.errors.push(error);
+                }
                 return Token::new(Kind::Eof, self.span);
             }
             break match token.kind {
+                cursor::Kind::BlockComment { terminated: false } => {
+                    // Point at opening `/*`
+                    let span = Span::new(self.span.file, self.span.start, self.span.start + 2);
+                    let error = LexerError::new(span, LexerErrorKind::UnterminatedBlockComment);
+                    self.errors.push(error);
+                    if self.lossless {
+                        Kind::Trivia(Trivia::BlockComment)
+                    } else {
+                        self.span.consume();
+                        continue;
+                    }
+                }
+                cursor::Kind::LineComment if self.lossless => Kind::Trivia(Trivia::LineComment),
+                cursor::Kind::BlockComment { .. } if self.lossless => {
+                    Kind::Trivia(Trivia::BlockComment)
+                }
+                cursor::Kind::WhiteSpace { .. } if self.lossless => Kind::Trivia(Trivia::WhiteSpace),
                 cursor::Kind::LineComment
                 | cursor::Kind::BlockComment { .. }
                 | cursor::Kind::WhiteSpace { .. } => {                    
@@ -118,6 +358,7 @@ impl<'a> Lexer<'a> {
                     continue;
                 }
                 cursor::Kind::Docs => Kind::Docs,
+                cursor::Kind::InnerDocs => Kind::InnerDocs,
                 cursor::Kind::Ident => self.parse_ident(),
                 cursor::Kind::InvalidIdent => self.parse_invalid_ident(),
                 cursor::Kind::Lit(kind) => self.parse_lit(kind),
@@ -151,10 +392,12 @@ impl<'a> Lexer<'a> {
                 },
                 cursor::Kind![<] => match_next! {
                     [=] => Kind![<=],
+                    [<] => overflow_assign_or_binary![<<],
                     _   => Kind![<],
                 },
                 cursor::Kind![>] => match_next! {
                     [=] => Kind![>=],
+                    [>] => overflow_assign_or_binary![>>],
                     _   => Kind![>],
                 },
                 cursor::Kind![&] => match_next! {
@@ -165,25 +408,44 @@ impl<'a> Lexer<'a> {
                     [|] => Kind![||],
                     _ => assign_or_binary![|],
                 },
-                cursor::Kind![+] => assign_or_binary![+],
+                cursor::Kind![+] => overflow_assign_or_binary![+],
                 cursor::Kind![-] => match_next! {
                     [>] => Kind![->],
-                    _ => assign_or_binary![-],
+                    _ => overflow_assign_or_binary![-],
                 },
-                cursor::Kind![*] => assign_or_binary![*],
-                cursor::Kind![/] => assign_or_binary![/],
+                cursor::Kind![*] => overflow_assign_or_binary![*],
+                cursor::Kind![/] => overflow_assign_or_binary![/],
                 cursor::Kind![^] => assign_or_binary![^],
                 cursor::Kind![%] => assign_or_binary![%],
                 cursor::Kind!['('] => Kind!['('],
                 cursor::Kind![')'] => Kind![')'],
-                cursor::Kind!['{'] => Kind!['{'],
-                cursor::Kind!['}'] => Kind!['}'],
+                cursor::Kind!['{'] => {
+                    if let Some(hole) = self.holes.last_mut() {
+                        hole.depth += 1;
+                    }
+                    Kind!['{']
+                }
+                cursor::Kind!['}'] => match self.holes.last_mut() {
+                    Some(hole) if hole.depth == 0 => {
+                        self.holes.pop();
+                        self.parse_str_continue()
+                    }
+                    Some(hole) => {
+                        hole.depth -= 1;
+                        Kind!['}']
+                    }
+                    None => Kind!['}'],
+                },
                 cursor::Kind!['['] => Kind!['['],
                 cursor::Kind![']'] => Kind![']'],
                 cursor::Kind::Unknown => Kind::Unknown,
                 cursor::Kind::Eof => unreachable!(),
             };
         };
+        if !matches!(kind, Kind::Trivia(_)) {
+            self.negation = kind == Kind![-] && !ends_operand(self.last);
+            self.last = kind;
+        }
         let token = Token::new(kind, self.span);
         self.span.consume();
         token
@@ -204,19 +466,35 @@ impl<'a> Lexer<'a> {
     // }
 
     fn parse_ident(&mut self) -> Kind {
-        match self.lexeme() {
-            "pub" => Kind::Keyword(Keyword::Pub),
-            "let" => Kind::Keyword(Keyword::Let),
-            "const" => Kind::Keyword(Keyword::Const),
-            "mut" => Kind::Keyword(Keyword::Mut),
-            "if" => Kind::Keyword(Keyword::If),
-            "else" => Kind::Keyword(Keyword::Else),
-            "while" => Kind::Keyword(Keyword::While),
-            "for" => Kind::Keyword(Keyword::For),
-            "in" => Kind::Keyword(Keyword::In),
-            "fn" => Kind::Keyword(Keyword::Fn),
-            "false" | "true" => Kind::Literal(Literal::bool()),
-            _ => Kind::Ident,
+        let lexeme = normalize_ident(self.lexeme());
+        if let Ok(keyword) = lexeme.parse::<Keyword>() {
+            return Kind::Keyword(keyword);
+        }
+        if let "false" | "true" = &*lexeme {
+            return Kind::Literal(Literal::bool());
+        }
+        self.check_confusables();
+        Kind::Ident
+    }
+
+    /// Warn about identifier which looks like ASCII one, but is not: `heаlth`
+    fn check_confusables(&mut self) {
+        let source = self.source;
+        let lexeme = &source[self.span.as_range()];
+        let looks_ascii = lexeme
+            .chars()
+            .all(|c| c.is_ascii() || confusable(c).is_some());
+        if lexeme.is_ascii() || !looks_ascii {
+            return;
+        }
+        for (index, found) in lexeme.char_indices() {
+            let Some(similar) = confusable(found) else {
+                continue;
+            };
+            let start = self.span.start + index;
+            let span = Span::new(self.span.file, start, start + found.len_utf8());
+            let kind = LexerWarningKind::Confusable { found, similar };
+            self.warnings.push(LexerWarning::new(span, kind));
         }
     }
 
@@ -247,6 +525,26 @@ impl<'a> Lexer<'a> {
                 }
                 LiteralKind::Str
             }
+            cursor::LitKind::StrHead => return self.parse_str_part(StrPart::Head),
+            cursor::LitKind::StrContinue { .. } => unreachable!(),
+            cursor::LitKind::Byte { terminated } => {
+                if !terminated {
+                    self.push_error(LexerErrorKind::UnterminatedByte);
+                }
+                LiteralKind::Byte
+            }
+            cursor::LitKind::ByteStr { terminated } => {
+                if !terminated {
+                    self.push_error(LexerErrorKind::UnterminatedByteStr);
+                }
+                LiteralKind::ByteStr
+            }
+            cursor::LitKind::RawStr { hashes, terminated } => {
+                if !terminated {
+                    self.push_error(LexerErrorKind::UnterminatedRawStr { hashes });
+                }
+                LiteralKind::RawStr { hashes }
+            }
         };
         let first = self.first();
         let suffix_len = match first.kind {
@@ -256,13 +554,65 @@ impl<'a> Lexer<'a> {
             }
             cursor::Kind::InvalidIdent => {
                 self.skip();
-                self.push_error(LexerErrorKind::InvalidSuffix);
                 first.len
             }
             _ => 0,
         };
+        let suffix = self.parse_suffix(kind, suffix_len);
         let suffix_len = suffix_len.try_into().unwrap();
-        Kind::Literal(Literal { kind, suffix_len })
+        let literal = Literal::new(kind, suffix_len, suffix);
+        // Report invalid digits, exponents, escapes and overflow
+        let negative = self.negation;
+        let _ = LitValue::eval(self.source, self.span, literal, negative, &mut self.errors);
+        Kind::Literal(literal)
+    }
+
+    /// Continue interpolated string after `}` closing the hole
+    fn parse_str_continue(&mut self) -> Kind {
+        let token = self.cursor.str_continue();
+        self.span.end += token.len as usize;
+        let cursor::Kind::Lit(cursor::LitKind::StrContinue { end }) = token.kind else {
+            unreachable!()
+        };
+        match end {
+            StrEnd::Hole => self.parse_str_part(StrPart::Middle),
+            StrEnd::Quote => self.parse_str_part(StrPart::Tail),
+            StrEnd::Unterminated => {
+                self.push_error(LexerErrorKind::UnterminatedStr);
+                self.parse_str_part(StrPart::Tail)
+            }
+        }
+    }
+
+    fn parse_str_part(&mut self, part: StrPart) -> Kind {
+        if matches!(part, StrPart::Head | StrPart::Middle) {
+            self.holes.push(Hole {
+                start: self.span.end - 1,
+                depth: 0,
+            });
+        }
+        // Report invalid escapes and unmatched `}`
+        let _ = LitValue::eval_str_part(self.source, self.span, &mut self.errors);
+        Kind::StrPart(part)
+    }
+
+    fn parse_suffix(&mut self, kind: LiteralKind, len: u32) -> Option<LitSuffix> {
+        if len == 0 {
+            return None;
+        }
+        let span = Span::new(self.span.file, self.span.end - len as usize, self.span.end);
+        let suffix = span
+            .lexeme(self.source)
+            .parse::<LitSuffix>()
+            .ok()
+            .filter(|suffix| suffix.accepts(kind));
+        if suffix.is_none() {
+            // Point only at suffix: `10banana`
+            //                         ^^^^^^
+            let error = LexerError::new(span, LexerErrorKind::InvalidSuffix);
+            self.errors.push(error);
+        }
+        suffix
     }
 }
 
@@ -272,6 +622,14 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// `kind` can end an operand, so `-` after it is subtraction, not negation
+fn ends_operand(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::Ident | Kind::Literal(_) | Kind::StrPart(StrPart::Tail) | Kind![')'] | Kind![']']
+    )
+}
+
 impl<'a> Lexer<'a> {
     fn skip_while<F>(&mut self, mut predicate: F)
     where
@@ -333,6 +691,7 @@ fn main() {
 }
 "#
     .trim();
+    assert_lossless(source);
     let mut lexer = Lexer::new(source);
     let mut count = 1u32;
     loop {
@@ -356,3 +715,271 @@ fn main() {
         println!("    | lexeme: {}", &source[span]);
     }
 }
+
+#[test]
+fn overflow_assign() {
+    let source = "a +%= b -#= c *?= d %= e + % f <<%= g >>?=";
+    let (tokens, errors) = Lexer::tokenize(source);
+    assert_lossless(source);
+    assert!(errors.is_empty());
+    let kinds: Vec<Kind> = tokens.iter().map(|token| token.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            Kind::Ident,
+            Kind::Assign(AssignKind::Plus, Some(OverflowMode::Wrapping)),
+            Kind::Ident,
+            Kind::Assign(AssignKind::Minus, Some(OverflowMode::Saturating)),
+            Kind::Ident,
+            Kind::Assign(AssignKind::Star, Some(OverflowMode::Checked)),
+            Kind::Ident,
+            Kind![%=],
+            Kind::Ident,
+            Kind![+],
+            Kind![%],
+            Kind::Ident,
+            Kind::Assign(AssignKind::Shl, Some(OverflowMode::Wrapping)),
+            Kind::Ident,
+            Kind::Assign(AssignKind::Shr, Some(OverflowMode::Checked)),
+        ]
+    );
+}
+
+#[test]
+fn suffix() {
+    let source = "10u8 2.5f32 100i64 10banana 2.5u8 'a'u8";
+    let (tokens, errors) = Lexer::tokenize(source);
+    assert_lossless(source);
+    let suffixes: Vec<_> = tokens
+        .iter()
+        .map(|token| match token.kind {
+            Kind::Literal(literal) => literal.suffix,
+            _ => panic!("expected literal, found {}", token.kind),
+        })
+        .collect();
+    assert_eq!(
+        suffixes,
+        [
+            Some(LitSuffix::U8),
+            Some(LitSuffix::F32),
+            Some(LitSuffix::I64),
+            None,
+            None,
+            None
+        ]
+    );
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|error| error.span.lexeme(source))
+        .collect();
+    assert_eq!(errors, ["banana", "u8", "u8"]);
+}
+
+#[test]
+fn float() {
+    let source = "1e9 2.5E-3 6.02e23 0.5 1e3f32 0x1.5 1.5e+";
+    let (tokens, errors) = Lexer::tokenize(source);
+    assert_lossless(source);
+    let lexemes: Vec<_> = tokens
+        .iter()
+        .map(|token| {
+            assert!(matches!(
+                token.kind,
+                Kind::Literal(Literal {
+                    kind: LiteralKind::Float { .. },
+                    ..
+                })
+            ));
+            token.span.lexeme(source)
+        })
+        .collect();
+    assert_eq!(
+        lexemes,
+        ["1e9", "2.5E-3", "6.02e23", "0.5", "1e3f32", "0x1.5", "1.5e+"]
+    );
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|error| error.span.lexeme(source))
+        .collect();
+    assert_eq!(errors, ["0x1.5", "e+"]);
+}
+
+#[test]
+fn block_comment() {
+    let source = "a /* outer /* inner */ still comment */ b /* open /* nested */";
+    let (tokens, errors) = Lexer::tokenize(source);
+    assert_lossless(source);
+    let lexemes: Vec<_> = tokens
+        .iter()
+        .map(|token| token.span.lexeme(source))
+        .collect();
+    assert_eq!(lexemes, ["a", "b"]);
+    assert!(matches!(
+        errors.as_slice(),
+        [LexerError {
+            kind: LexerErrorKind::UnterminatedBlockComment,
+            ..
+        }]
+    ));
+    assert_eq!(errors[0].span.as_range(), 42..44);
+}
+
+#[test]
+fn raw_str() {
+    let source = r####"r#"a "b" c"# b"x" b'y' r##"open"#"####;
+    let (tokens, errors) = Lexer::tokenize(source);
+    assert_lossless(source);
+    let kinds: Vec<_> = tokens
+        .iter()
+        .map(|token| match token.kind {
+            Kind::Literal(literal) => literal.kind,
+            _ => panic!("expected literal, found {}", token.kind),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            LiteralKind::RawStr { hashes: 1 },
+            LiteralKind::ByteStr,
+            LiteralKind::Byte,
+            LiteralKind::RawStr { hashes: 2 },
+        ]
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [LexerError {
+            kind: LexerErrorKind::UnterminatedRawStr { hashes: 2 },
+            ..
+        }]
+    ));
+
+    // Multibyte char right before the missing end
+    for source in [r##"r#"éa"##, r#"r"é"#, r##"r#"aé""##] {
+        let (_, errors) = Lexer::tokenize(source);
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [LexerError {
+                    kind: LexerErrorKind::UnterminatedRawStr { .. },
+                    ..
+                }]
+            ),
+            "{source}: {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn interpolation() {
+    let source = r#""hp: {health}/{max(a, {b})}!" "{{x}}" "a } b" "open {x"#;
+    let (tokens, errors) = Lexer::tokenize(source);
+    assert_lossless(source);
+    let lexemes: Vec<_> = tokens
+        .iter()
+        .map(|token| token.span.lexeme(source))
+        .collect();
+    assert_eq!(
+        lexemes,
+        [
+            "\"hp: {",
+            "health",
+            "}/{",
+            "max",
+            "(",
+            "a",
+            ",",
+            "{",
+            "b",
+            "}",
+            ")",
+            "}!\"",
+            "\"{{x}}\"",
+            "\"a } b\"",
+            "\"open {",
+            "x",
+        ]
+    );
+    assert_eq!(tokens[0].kind, Kind::StrPart(StrPart::Head));
+    assert_eq!(tokens[2].kind, Kind::StrPart(StrPart::Middle));
+    assert_eq!(tokens[11].kind, Kind::StrPart(StrPart::Tail));
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|error| (error.kind, error.span.lexeme(source)))
+        .collect();
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            (LexerErrorKind::UnmatchedBrace, "}"),
+            (LexerErrorKind::UnterminatedHole, "{"),
+        ]
+    ));
+}
+
+#[test]
+fn unicode_ident() {
+    let source = "здоровье _имя café cafe\u{301} heаlth hp😀";
+    assert_lossless(source);
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.advance_token();
+        if let Kind::Eof = token.kind {
+            break;
+        }
+        assert_eq!(token.kind, Kind::Ident);
+        tokens.push(token.span.lexeme(source));
+    }
+    assert_eq!(
+        tokens,
+        ["здоровье", "_имя", "café", "cafe\u{301}", "heаlth", "hp😀"]
+    );
+    assert_eq!(normalize_ident(tokens[2]), normalize_ident(tokens[3]));
+    assert!(matches!(
+        lexer.errors.as_slice(),
+        [LexerError {
+            kind: LexerErrorKind::InvalidIdent,
+            ..
+        }]
+    ));
+    assert!(matches!(
+        lexer.warnings.as_slice(),
+        [LexerWarning {
+            kind: LexerWarningKind::Confusable {
+                found: 'а',
+                similar: 'a'
+            },
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn lossless() {
+    let source = std::fs::read_to_string("main.rw").unwrap();
+    assert_lossless(&source);
+    assert_lossless("a // comment\r\n/* block /* nested */ */\tb /// docs\n c /* open");
+}
+
+/// Check that lossless tokens reproduce `source`
+/// and are the same as usual ones without trivia
+#[cfg(test)]
+fn assert_lossless(source: &str) {
+    let (lossless, _) = Lexer::tokenize_lossless(source);
+    let text: String = lossless
+        .iter()
+        .map(|token| token.span.lexeme(source))
+        .collect();
+    assert_eq!(text, source);
+
+    let (tokens, _) = Lexer::tokenize(source);
+    let lossless: Vec<_> = lossless
+        .iter()
+        .filter(|token| !matches!(token.kind, Kind::Trivia(_)))
+        .map(|token| (token.kind, token.span.as_range()))
+        .collect();
+    let tokens: Vec<_> = tokens
+        .iter()
+        .map(|token| (token.kind, token.span.as_range()))
+        .collect();
+    assert_eq!(lossless, tokens);
+}
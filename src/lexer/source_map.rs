@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use super::{ColumnUnit, LineIndex, Location, Span};
+
+/// Id of file in [SourceMap]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FileId(pub u32);
+
+impl FileId {
+    pub fn as_index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Source file with its path and [LineIndex]
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    path: String,
+    source: String,
+    lines: LineIndex,
+}
+
+impl SourceFile {
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn lines(&self) -> &LineIndex {
+        &self.lines
+    }
+}
+
+/// Owner of all source files, every [Span] is resolved through it
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+/// [Span] resolved to its file and lines
+#[derive(Debug, Clone, Copy)]
+pub struct SpanLocation<'a> {
+    pub file: &'a SourceFile,
+    pub start: Location,
+    pub end: Location,
+    /// Whole lines of source containing span, without the last line ending
+    pub snippet: &'a str,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, path: impl Into<String>, source: impl Into<String>) -> FileId {
+        let id = FileId(self.files.len() as u32);
+        let source = source.into();
+        self.files.push(SourceFile {
+            path: path.into(),
+            lines: LineIndex::new(&source),
+            source,
+        });
+        id
+    }
+
+    /// Read file at `path` and add it
+    pub fn load(&mut self, path: impl AsRef<Path>) -> std::io::Result<FileId> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+        Ok(self.add(path.display().to_string(), source))
+    }
+
+    /// # Panics
+    /// Panics if `id` is not from this map
+    #[track_caller]
+    pub fn file(&self, id: FileId) -> &SourceFile {
+        &self.files[id.as_index()]
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = (FileId, &SourceFile)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| (FileId(index as u32), file))
+    }
+
+    pub fn lexeme(&self, span: Span) -> &str {
+        span.lexeme(self.file(span.file).source())
+    }
+
+    /// Resolve `span` to its file, lines and snippet, columns are counted in `unit`
+    pub fn locate(&self, span: Span, unit: ColumnUnit) -> SpanLocation<'_> {
+        let file = self.file(span.file);
+        let start = file.lines.location(span.start, unit);
+        let end = file.lines.location(span.end, unit);
+        let snippet_start = file.lines.line_start(start.line).unwrap_or(0);
+        let snippet_end = file.lines.line_end(end.line).unwrap_or(file.source.len());
+        SpanLocation {
+            file,
+            start,
+            end,
+            snippet: file.source[snippet_start..snippet_end].trim_end_matches('\r'),
+        }
+    }
+}
+
+#[test]
+fn source_map() {
+    use super::{Kind, Lexer};
+
+    let mut sources = SourceMap::new();
+    let first = sources.add("first.rw", "let a = 1;");
+    let second = sources.add("second.rw", "fn main() {\n    let hp = 100;\r\n}");
+    assert_ne!(first, second);
+
+    let mut lexer = Lexer::with_file(second, sources.file(second).source());
+    let hp = loop {
+        let token = lexer.advance_token();
+        match token.kind {
+            Kind::Ident if sources.lexeme(token.span) == "hp" => break token.span,
+            Kind::Eof => panic!("expected `hp`"),
+            _ => (),
+        }
+    };
+    assert_eq!(hp.file(), second);
+    let location = sources.locate(hp, ColumnUnit::Utf8);
+    assert_eq!(location.file.path(), "second.rw");
+    assert_eq!(location.start, Location::new(2, 9));
+    assert_eq!(location.end, Location::new(2, 11));
+    assert_eq!(location.snippet, "    let hp = 100;");
+}
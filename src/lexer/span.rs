@@ -1,26 +1,42 @@
 use std::ops::Range;
 
-use super::Location;
+use super::FileId;
 
-#[derive(Debug, Clone, Copy)]
+/// Byte range in the source of `file`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Span {
+    pub(crate) file: FileId,
     pub(crate) start: usize,
     pub(crate) end: usize,
 }
 
 impl Span {
     #[track_caller]
-    pub const fn new(start: usize, end: usize) -> Self {
+    pub const fn new(file: FileId, start: usize, end: usize) -> Self {
         debug_assert!(start <= end);
-        Self { start, end }
+        Self { file, start, end }
     }
 
-    pub const fn eof(end: usize) -> Self {
-        Self { start: end, end }
+    pub const fn eof(file: FileId, end: usize) -> Self {
+        Self {
+            file,
+            start: end,
+            end,
+        }
     }
 
-    pub const fn zero() -> Self {
-        Self { start: 0, end: 0 }
+    pub const fn zero(file: FileId) -> Self {
+        Self {
+            file,
+            start: 0,
+            end: 0,
+        }
+    }
+}
+
+impl Span {
+    pub const fn file(&self) -> FileId {
+        self.file
     }
 }
 
@@ -35,33 +51,6 @@ impl Span {
 }
 
 impl Span {
-    /// # Panics
-    /// Panics if source does not contain span
-    #[track_caller]
-    pub fn location(&self, source: &str) -> Location {
-        if source.len() < self.end {
-            panic!("Span::location(source): source does not contain span!")
-        }
-
-        let mut line = 1;
-        let mut column = 1;
-
-        for (index, c) in source.chars().enumerate() {
-            if index == self.start {
-                break;
-            }
-
-            if c == '\n' {
-                line += 1;
-                column = 1;
-            } else {
-                column += c.len_utf8() as u32;
-            }
-        }
-
-        Location::new(line, column)
-    }
-
     pub fn get_lexeme<'a>(&self, source: &'a str) -> Option<&'a str> {
         source.get(self.as_range())
     }
@@ -73,4 +62,8 @@ impl Span {
     pub fn len(&self) -> u32 {
         (self.end - self.start) as u32
     }
+
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
 }
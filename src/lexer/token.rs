@@ -16,6 +16,14 @@ impl Token {
     pub const fn new(kind: Kind, span: Span) -> Self {
         Self { kind, span }
     }
+
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    pub const fn span(&self) -> Span {
+        self.span
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,6 +43,10 @@ impl TokenId {
 pub enum Kind {
     /// `/// Docs`
     Docs,
+    /// `//! Docs`
+    InnerDocs,
+    /// Only in lossless mode, look [super::Lexer::lossless]
+    Trivia(Trivia),
     /// `!`
     Not,
     /// `~`
@@ -70,8 +82,10 @@ pub enum Kind {
     Ident,
     Keyword(Keyword),
     Literal(Literal),
+    /// Part of interpolated string, look [StrPart]
+    StrPart(StrPart),
     Binary(BinaryKind),
-    Assign(AssignKind),
+    Assign(AssignKind, Option<OverflowMode>),
     OpenDelim(Delimiter),
     CloseDelim(Delimiter),
     Unknown,
@@ -82,6 +96,8 @@ impl core::fmt::Display for Kind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Docs => write!(f, "Docs"),
+            Self::InnerDocs => write!(f, "InnerDocs"),
+            Self::Trivia(trivia) => write!(f, "{trivia}"),
             Self::Not => write!(f, "!"),
             Self::Tilde => write!(f, "~"),
             Self::At => write!(f, "@"),
@@ -101,8 +117,13 @@ impl core::fmt::Display for Kind {
             Self::Ident => write!(f, "Ident"),
             Self::Keyword(keyword) => write!(f, "{keyword}"),
             Self::Literal(literal) => write!(f, "{literal}"),
+            Self::StrPart(_) => write!(f, "string"),
             Self::Binary(kind) => write!(f, "{kind}"),
-            Self::Assign(kind) => write!(f, "{kind}"),
+            Self::Assign(kind, None) => write!(f, "{kind}"),
+            Self::Assign(kind, Some(mode)) => match kind.as_binary() {
+                Some(binary) => write!(f, "{binary}{mode}="),
+                None => write!(f, "{kind}"),
+            },
             Self::OpenDelim(delim) => {
                 let c = match delim {
                     Delimiter::Paren => '(',
@@ -129,13 +150,44 @@ impl Kind {
     pub fn is_simple(&self) -> bool {
         !matches!(
             self,
-            Self::Docs | Self::Ident | Self::Literal { .. } | Self::Unknown | Self::Eof
+            Self::Docs
+                | Self::InnerDocs
+                | Self::Trivia(_)
+                | Self::Ident
+                | Self::Literal { .. }
+                | Self::StrPart(_)
+                | Self::Unknown
+                | Self::Eof
         )
     }
 }
 
 // endregion: ----- Kind -----
 
+// region: ----- Trivia -----
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trivia {
+    /// Any spacing
+    WhiteSpace,
+    /// `// comment`
+    LineComment,
+    /// `/* comment */`
+    BlockComment,
+}
+
+impl core::fmt::Display for Trivia {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WhiteSpace => write!(f, "WhiteSpace"),
+            Self::LineComment => write!(f, "LineComment"),
+            Self::BlockComment => write!(f, "BlockComment"),
+        }
+    }
+}
+
+// endregion: ----- Trivia -----
+
 // region: ----- Keyword -----
 
 macro_rules! keywords {
@@ -170,16 +222,19 @@ macro_rules! keywords {
 }
 
 keywords! {
-    "pub"   => Pub,
-    "let"   => Let,
-    "const" => Const,
-    "mut"   => Mut,
-    "if"    => If,
-    "else"  => Else,
-    "while" => While,
-    "for"   => For,
-    "in"    => In,
-    "fn"    => Fn,
+    "pub"      => Pub,
+    "let"      => Let,
+    "const"    => Const,
+    "mut"      => Mut,
+    "if"       => If,
+    "else"     => Else,
+    "while"    => While,
+    "for"      => For,
+    "in"       => In,
+    "fn"       => Fn,
+    "break"    => Break,
+    "continue" => Continue,
+    "return"   => Return,
 }
 
 // endregion: ----- Keyword -----
@@ -190,6 +245,8 @@ keywords! {
 pub struct Literal {
     pub kind: LiteralKind,
     pub suffix_len: u16,
+    /// Valid suffix like `u8` in `10u8`
+    pub suffix: Option<LitSuffix>,
 }
 
 impl core::fmt::Display for Literal {
@@ -199,12 +256,16 @@ impl core::fmt::Display for Literal {
 }
 
 impl Literal {
-    pub const fn new(kind: LiteralKind, suffix_len: u16) -> Self {
-        Self { kind, suffix_len }
+    pub const fn new(kind: LiteralKind, suffix_len: u16, suffix: Option<LitSuffix>) -> Self {
+        Self {
+            kind,
+            suffix_len,
+            suffix,
+        }
     }
 
     pub const fn bool() -> Self {
-        Self::new(LiteralKind::Bool, 0)
+        Self::new(LiteralKind::Bool, 0, None)
     }
 }
 
@@ -217,6 +278,9 @@ pub enum LiteralKind {
     Bool,
     Char,
     Str,
+    Byte,
+    ByteStr,
+    RawStr { hashes: u32 },
 }
 
 impl core::fmt::Display for LiteralKind {
@@ -227,12 +291,135 @@ impl core::fmt::Display for LiteralKind {
             Self::Bool => write!(f, "bool"),
             Self::Char => write!(f, "char"),
             Self::Str => write!(f, "string"),
+            Self::Byte => write!(f, "byte"),
+            Self::ByteStr => write!(f, "byte string"),
+            Self::RawStr { .. } => write!(f, "raw string"),
         }
     }
 }
 
 // endregion: ----- LiteralKind -----
 
+// region: ----- LitSuffix -----
+
+/// Type suffix of numeric literal like `u8` in `10u8`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LitSuffix {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    F32,
+    F64,
+}
+
+impl LitSuffix {
+    pub const fn is_float(&self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+
+    /// Integers accept any suffix (`1f32`), floats only float suffixes
+    pub const fn accepts(&self, kind: LiteralKind) -> bool {
+        match kind {
+            LiteralKind::Int { .. } => true,
+            LiteralKind::Float { .. } => self.is_float(),
+            _ => false,
+        }
+    }
+
+    /// Max value of literal with integer suffix.
+    ///
+    /// For `negative` signed types it is `MAX + 1`, because `-128i8` is negation of `128i8`.
+    pub const fn max_int(&self, negative: bool) -> Option<u128> {
+        let minus = negative as u128;
+        Some(match self {
+            Self::U8 => u8::MAX as u128,
+            Self::U16 => u16::MAX as u128,
+            Self::U32 => u32::MAX as u128,
+            Self::U64 | Self::Usize => u64::MAX as u128,
+            Self::U128 => u128::MAX,
+            Self::I8 => i8::MAX as u128 + minus,
+            Self::I16 => i16::MAX as u128 + minus,
+            Self::I32 => i32::MAX as u128 + minus,
+            Self::I64 | Self::Isize => i64::MAX as u128 + minus,
+            Self::I128 => i128::MAX as u128 + minus,
+            Self::F32 | Self::F64 => return None,
+        })
+    }
+}
+
+impl core::fmt::Display for LitSuffix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let suffix = match self {
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+            Self::Usize => "usize",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+            Self::Isize => "isize",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        };
+        write!(f, "{suffix}")
+    }
+}
+
+impl FromStr for LitSuffix {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "u8" => Self::U8,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "u128" => Self::U128,
+            "usize" => Self::Usize,
+            "i8" => Self::I8,
+            "i16" => Self::I16,
+            "i32" => Self::I32,
+            "i64" => Self::I64,
+            "i128" => Self::I128,
+            "isize" => Self::Isize,
+            "f32" => Self::F32,
+            "f64" => Self::F64,
+            _ => return Err(()),
+        })
+    }
+}
+
+// endregion: ----- LitSuffix -----
+
+// region: ----- StrPart -----
+
+/// Interpolated string `"hp: {health}/{max}!"` is lexed into parts
+/// with tokens of embedded expressions between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrPart {
+    /// `"hp: {`
+    Head,
+    /// `}/{`
+    Middle,
+    /// `}!"`
+    Tail,
+}
+
+// endregion: ----- StrPart -----
+
 // endregion: ----- Literal -----
 
 // region: ----- BinaryKind -----
@@ -350,8 +537,52 @@ impl core::fmt::Display for AssignKind {
     }
 }
 
+impl AssignKind {
+    /// Binary operator of compound assignment: `+=` -> `+`
+    pub const fn as_binary(&self) -> Option<BinaryKind> {
+        Some(match self {
+            Self::Eq => return None,
+            Self::Plus => BinaryKind::Plus,
+            Self::Minus => BinaryKind::Minus,
+            Self::Star => BinaryKind::Star,
+            Self::Slash => BinaryKind::Slash,
+            Self::Percent => BinaryKind::Percent,
+            Self::Caret => BinaryKind::Caret,
+            Self::Or => BinaryKind::BinOr,
+            Self::And => BinaryKind::BinAnd,
+            Self::Shl => BinaryKind::Shl,
+            Self::Shr => BinaryKind::Shr,
+        })
+    }
+}
+
 // endregion: ----- AssignKind -----
 
+// region: ----- OverflowMode -----
+
+/// Overflow behaviour of compound assignment like `+%=`, `<<#=` or `>>?=`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// `%`
+    Wrapping,
+    /// `#`
+    Saturating,
+    /// `?`
+    Checked,
+}
+
+impl core::fmt::Display for OverflowMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Wrapping => write!(f, "%"),
+            Self::Saturating => write!(f, "#"),
+            Self::Checked => write!(f, "?"),
+        }
+    }
+}
+
+// endregion: ----- OverflowMode -----
+
 // region: ----- Delimiter -----
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
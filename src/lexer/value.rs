@@ -0,0 +1,380 @@
+use std::{iter::Peekable, ops::Range, str::CharIndices};
+
+use crate::cursor::Base;
+
+use super::{FileId, LexerError, LexerErrorKind, LitSuffix, Literal, LiteralKind, Span};
+
+/// Value of literal token
+#[derive(Debug, Clone, PartialEq)]
+pub enum LitValue {
+    Int(u128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Str(String),
+    Byte(u8),
+    ByteStr(Vec<u8>),
+}
+
+impl LitValue {
+    /// Evaluate `literal` with `span` in `source`, `negative` if it is negated
+    /// like `-128i8`.
+    ///
+    /// Problems are pushed to `errors` with spans pointing inside the literal.
+    /// Errors reported by [super::Lexer] itself (like `EmptyInt`) are not repeated.
+    pub fn eval(
+        source: &str,
+        span: Span,
+        literal: Literal,
+        negative: bool,
+        errors: &mut Vec<LexerError>,
+    ) -> Result<Self, ()> {
+        let end = span.end - literal.suffix_len as usize;
+        let text = &source[span.start..end];
+        let error_count = errors.len();
+        let mut eval = Eval {
+            file: span.file,
+            start: span.start,
+            errors,
+        };
+        let value = match literal.kind {
+            LiteralKind::Int { base } => {
+                let value = eval.int(text, base);
+                match literal.suffix {
+                    // `1f32`
+                    Some(suffix) if suffix.is_float() => Self::Float(value as f64),
+                    Some(suffix) => {
+                        if suffix.max_int(negative).is_some_and(|max| value > max) {
+                            eval.push_error(0..text.len(), LexerErrorKind::LiteralOverflow);
+                        }
+                        Self::Int(value)
+                    }
+                    None => Self::Int(value),
+                }
+            }
+            LiteralKind::Float { base } => {
+                let value = eval.float(text, base);
+                if let Some(LitSuffix::F32) = literal.suffix {
+                    if (value as f32).is_infinite() {
+                        eval.push_error(0..text.len(), LexerErrorKind::LiteralOverflow);
+                    }
+                }
+                Self::Float(value)
+            }
+            LiteralKind::Bool => Self::Bool(text == "true"),
+            LiteralKind::Char => Self::Char(eval.char(text, 1, false)),
+            LiteralKind::Str => Self::Str(eval.str(text, 1, false)),
+            // Chars of byte literals are always less than `0x100`
+            LiteralKind::Byte => Self::Byte(eval.char(text, 2, true) as u8),
+            LiteralKind::ByteStr => {
+                Self::ByteStr(eval.str(text, 2, true).chars().map(|c| c as u8).collect())
+            }
+            LiteralKind::RawStr { hashes } => Self::Str(raw_str(text, hashes).to_owned()),
+        };
+        if errors.len() == error_count {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+
+    /// Evaluate text of interpolated string part with `span`: `"hp: {`, `}/{` or `}!"`.
+    ///
+    /// Value is always [LitValue::Str].
+    pub fn eval_str_part(
+        source: &str,
+        span: Span,
+        errors: &mut Vec<LexerError>,
+    ) -> Result<Self, ()> {
+        let text = &source[span.as_range()];
+        let error_count = errors.len();
+        let mut eval = Eval {
+            file: span.file,
+            start: span.start,
+            errors,
+        };
+        // Skip `"` or `}`
+        let value = Self::Str(eval.str(text, 1, false));
+        if errors.len() == error_count {
+            Ok(value)
+        } else {
+            Err(())
+        }
+    }
+}
+
+struct Eval<'e> {
+    file: FileId,
+    start: usize,
+    errors: &'e mut Vec<LexerError>,
+}
+
+impl Eval<'_> {
+    fn int(&mut self, text: &str, base: Base) -> u128 {
+        // Skip `0b`, `0o` or `0x`
+        let offset = if matches!(base, Base::Decimal) { 0 } else { 2 };
+        let mut value = 0u128;
+        let mut overflow = false;
+        for (index, c) in text[offset..].char_indices() {
+            if c == '_' {
+                continue;
+            }
+            let index = offset + index;
+            let Some(digit) = c.to_digit(base as u32) else {
+                self.push_error(
+                    index..index + c.len_utf8(),
+                    LexerErrorKind::InvalidDigit { base },
+                );
+                continue;
+            };
+            if overflow {
+                continue;
+            }
+            match value
+                .checked_mul(base as u128)
+                .and_then(|value| value.checked_add(digit as u128))
+            {
+                Some(next) => value = next,
+                None => overflow = true,
+            }
+        }
+        if overflow {
+            self.push_error(0..text.len(), LexerErrorKind::LiteralOverflow);
+        }
+        value
+    }
+
+    fn float(&mut self, text: &str, base: Base) -> f64 {
+        if base != Base::Decimal {
+            self.push_error(0..text.len(), LexerErrorKind::NonDecimalFloat);
+            return 0.0;
+        }
+        let Ok(value) = text.replace('_', "").parse::<f64>() else {
+            // Cursor takes only digits, so just exponent can be empty: `1.5e+`
+            //                                                              ^^
+            let offset = text.rfind(['e', 'E']).unwrap_or(0);
+            self.push_error(offset..text.len(), LexerErrorKind::EmptyExponent);
+            return 0.0;
+        };
+        if value.is_infinite() {
+            self.push_error(0..text.len(), LexerErrorKind::LiteralOverflow);
+        }
+        value
+    }
+
+    /// Char or byte (`bytes`) literal, `prefix` is length of `'` or `b'`
+    fn char(&mut self, text: &str, prefix: usize, bytes: bool) -> char {
+        let value = self.unescape(&text[prefix..], prefix, '\'', bytes);
+        let mut chars = value.chars();
+        // Unterminated char is reported by lexer, its length is unknown
+        if !is_terminated(&text[prefix..]) {
+            return chars.next().unwrap_or('\0');
+        }
+        let Some(c) = chars.next() else {
+            self.push_error(0..text.len(), LexerErrorKind::EmptyChar);
+            return '\0';
+        };
+        if chars.next().is_some() {
+            let end = text.len() - text.ends_with('\'') as usize;
+            self.push_error(prefix..end, LexerErrorKind::MultipleChars);
+        }
+        c
+    }
+
+    /// String or byte string (`bytes`) literal, `prefix` is length of `"` or `b"`
+    fn str(&mut self, text: &str, prefix: usize, bytes: bool) -> String {
+        self.unescape(&text[prefix..], prefix, '"', bytes)
+    }
+
+    /// Unescape `text` until closing `quote`, `offset` is position of `text` in literal.
+    ///
+    /// With `bytes` only ASCII characters and `\x00`..`\xFF` escapes are allowed.
+    /// Strings stop at `{` of interpolation hole, braces are escaped as `{{` and `}}`.
+    fn unescape(&mut self, text: &str, offset: usize, quote: char, bytes: bool) -> String {
+        let holes = quote == '"' && !bytes;
+        // Keeps char count for `MultipleChars` check
+        let placeholder = if bytes {
+            '\0'
+        } else {
+            char::REPLACEMENT_CHARACTER
+        };
+        let mut value = String::with_capacity(text.len());
+        let mut chars = text.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c == quote {
+                break;
+            }
+            if holes && matches!(c, '{' | '}') {
+                if chars.next_if(|&(_, next)| next == c).is_some() {
+                    value.push(c);
+                } else if c == '{' {
+                    break;
+                } else {
+                    let start = offset + start;
+                    self.push_error(start..start + 1, LexerErrorKind::UnmatchedBrace);
+                }
+                continue;
+            }
+            if c != '\\' {
+                if bytes && !c.is_ascii() {
+                    let end = offset + start + c.len_utf8();
+                    self.push_error(offset + start..end, LexerErrorKind::NonAsciiByte);
+                    value.push(placeholder);
+                } else {
+                    value.push(c);
+                }
+                continue;
+            }
+            // Unterminated literal is reported by lexer
+            let Some((_, escape)) = chars.next() else {
+                break;
+            };
+            let c = match escape {
+                'n' => Some('\n'),
+                'r' => Some('\r'),
+                't' => Some('\t'),
+                '\\' => Some('\\'),
+                '0' => Some('\0'),
+                '\'' => Some('\''),
+                '"' => Some('"'),
+                // `\x7F`, for bytes up to `\xFF`
+                'x' => hex_escape(&mut chars).filter(|c| bytes || c.is_ascii()),
+                // `\u{1F600}`
+                'u' => unicode_escape(&mut chars).filter(|_| !bytes),
+                // Multi-line string: `\` at the end of line skips leading whitespace
+                '\n' | '\r' if quote == '"' => {
+                    while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+                    continue;
+                }
+                _ => None,
+            };
+            match c {
+                Some(c) => value.push(c),
+                None => {
+                    value.push(placeholder);
+                    let end = chars.peek().map_or(text.len(), |&(index, _)| index);
+                    self.push_error(offset + start..offset + end, LexerErrorKind::InvalidEscape);
+                }
+            }
+        }
+        value
+    }
+
+    fn push_error(&mut self, range: Range<usize>, kind: LexerErrorKind) {
+        let span = Span::new(self.file, self.start + range.start, self.start + range.end);
+        self.errors.push(LexerError::new(span, kind));
+    }
+}
+
+/// Content of raw string `r#"..."#` with `hashes` count of `#`
+fn raw_str(text: &str, hashes: u32) -> &str {
+    let hashes = hashes as usize;
+    // Skip `r`, `#`s and `"`
+    let content = &text[hashes + 2..];
+    let closing = format!("\"{}", "#".repeat(hashes));
+    // Unterminated raw string is reported by lexer
+    content.strip_suffix(&closing).unwrap_or(content)
+}
+
+/// Check if char literal without prefix ends with not escaped `'`
+fn is_terminated(text: &str) -> bool {
+    let Some(inner) = text.strip_suffix('\'') else {
+        return false;
+    };
+    let slashes = inner.len() - inner.trim_end_matches('\\').len();
+    slashes % 2 == 0
+}
+
+fn hex_escape(chars: &mut Peekable<CharIndices>) -> Option<char> {
+    let mut value = 0;
+    for _ in 0..2 {
+        let digit = chars.peek().and_then(|&(_, c)| c.to_digit(16))?;
+        chars.next();
+        value = value * 16 + digit;
+    }
+    char::from_u32(value)
+}
+
+fn unicode_escape(chars: &mut Peekable<CharIndices>) -> Option<char> {
+    chars.next_if(|&(_, c)| c == '{')?;
+    let mut value = 0;
+    let mut len = 0;
+    while let Some((_, c)) = chars.next_if(|&(_, c)| c.is_ascii_hexdigit()) {
+        value = value * 16 + c.to_digit(16)?;
+        len += 1;
+        if len > 6 {
+            return None;
+        }
+    }
+    chars.next_if(|&(_, c)| c == '}')?;
+    if len == 0 {
+        return None;
+    }
+    char::from_u32(value)
+}
+
+#[test]
+fn eval() {
+    use super::{Kind, Lexer};
+
+    fn eval(source: &str) -> (Result<LitValue, ()>, Vec<Range<usize>>) {
+        let mut lexer = Lexer::new(source);
+        let token = lexer.advance_token();
+        let Kind::Literal(literal) = token.kind else {
+            panic!("expected literal, found {}", token.kind);
+        };
+        let mut errors = Vec::new();
+        let value = LitValue::eval(source, token.span, literal, false, &mut errors);
+        let spans = errors.iter().map(|error| error.span.as_range()).collect();
+        (value, spans)
+    }
+
+    assert_eq!(eval("0xFF_FF").0, Ok(LitValue::Int(0xFFFF)));
+    assert_eq!(eval("1_000").0, Ok(LitValue::Int(1000)));
+    assert_eq!(eval("1.25").0, Ok(LitValue::Float(1.25)));
+    assert_eq!(eval(r"'\n'").0, Ok(LitValue::Char('\n')));
+    assert_eq!(eval(r"'\u{1F600}'").0, Ok(LitValue::Char('😀')));
+    assert_eq!(eval(r#""a\"b""#).0, Ok(LitValue::Str("a\"b".into())));
+    assert_eq!(eval("0b102"), (Err(()), vec![4..5]));
+    assert_eq!(eval("0o8").1, vec![2..3]);
+    assert_eq!(
+        eval("340282366920938463463374607431768211456").1,
+        vec![0..39]
+    );
+    assert_eq!(eval(r#""a\qb""#).1, vec![2..4]);
+    assert_eq!(eval(r"'\x80'").1, vec![1..5]);
+    assert_eq!(eval("'ab'").1, vec![1..3]);
+    assert_eq!(eval("''").1, vec![0..2]);
+    assert_eq!(eval(r"'\\'").0, Ok(LitValue::Char('\\')));
+    // Unterminated chars are reported only by lexer
+    assert_eq!(eval("'é; }").1, vec![]);
+    assert_eq!(eval("'").1, vec![]);
+    assert_eq!(eval(r"'\'").1, vec![]);
+    assert_eq!(eval("10f32").0, Ok(LitValue::Float(10.0)));
+    assert_eq!(eval("256u8").1, vec![0..3]);
+    assert_eq!(eval("128i8").1, vec![0..3]);
+    assert_eq!(eval("127i8").0, Ok(LitValue::Int(127)));
+    // `-128i8` fits, but `- 128i8` after operand is subtraction
+    let (_, errors) = Lexer::tokenize("-128i8 + x - 128i8");
+    let [error] = errors.as_slice() else {
+        panic!("expected one error, found {errors:?}");
+    };
+    assert_eq!(error.span.as_range(), 13..16);
+    // Float without value is an error, not `0.0`
+    assert_eq!(eval("1e").0, Err(()));
+    assert_eq!(eval("1e").1, vec![1..2]);
+    assert_eq!(eval("2.5E-").1, vec![3..5]);
+    assert_eq!(eval("0x1.5").1, vec![0..5]);
+    assert_eq!(eval(r#"b'\xFF'"#).0, Ok(LitValue::Byte(0xFF)));
+    assert_eq!(eval(r#"b"a\n""#).0, Ok(LitValue::ByteStr(b"a\n".to_vec())));
+    assert_eq!(eval("b'é'").1, vec![2..4]);
+    assert_eq!(eval(r#"b"\u{41}""#).1, vec![2..8]);
+    assert_eq!(
+        eval(r##"r#"a"b\n"#"##).0,
+        Ok(LitValue::Str(r#"a"b\n"#.into()))
+    );
+    assert_eq!(eval(r#"r"\""#).0, Ok(LitValue::Str(r"\".into())));
+    assert_eq!(eval(r##"r#"éa"##).0, Ok(LitValue::Str("éa".into())));
+    assert_eq!(eval(r#"r"é"#).0, Ok(LitValue::Str("é".into())));
+    assert_eq!(eval("\"a\\\n    b\"").0, Ok(LitValue::Str("ab".into())));
+}
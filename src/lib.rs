@@ -1,3 +1,12 @@
 mod cursor;
+mod diagnostic;
+mod frontend;
 mod lexer;
 mod parser;
+
+pub use diagnostic::{
+    apply_fixes, codes, explain, Applicability, Code, Diagnostic, Edit, JsonEmitter, Label,
+    Renderer, Severity, Suggestion,
+};
+pub use frontend::{check_file, Format, Frontend, ParsedFile, DEFAULT_MAX_ERRORS};
+pub use lexer::{FileId, Kind, Lexer, LexerError, SourceMap, Span, Token, Trivia};
@@ -1 +1,51 @@
-fn main() {}
\ No newline at end of file
+use std::{
+    io::{self, IsTerminal},
+    process::ExitCode,
+};
+
+use lore::Format;
+
+const USAGE: &str = "\
+usage: lore check [--json] <file>
+       lore explain <code>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["check", "--json", path] => check(path, Format::Json),
+        ["check", path] => {
+            let colors = io::stderr().is_terminal();
+            check(path, Format::Text { colors })
+        }
+        ["explain", code] => match lore::explain(code) {
+            Some(explanation) => {
+                print!("{explanation}");
+                ExitCode::SUCCESS
+            }
+            None => {
+                eprintln!("error: unknown diagnostic code `{code}`");
+                ExitCode::FAILURE
+            }
+        },
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn check(path: &str, format: Format) -> ExitCode {
+    let result = match format {
+        Format::Json => lore::check_file(path, format, &mut io::stdout().lock()),
+        Format::Text { .. } => lore::check_file(path, format, &mut io::stderr().lock()),
+    };
+    match result {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: can not read `{path}`: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
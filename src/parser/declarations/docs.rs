@@ -0,0 +1,59 @@
+use crate::{
+    lexer::{self, Kind, TokenId},
+    parser::{Parse, Parser},
+};
+
+/// Doc comments: `/// docs` before item or `//! docs` at the start of file
+#[derive(Debug, Clone, Default)]
+pub struct Docs(pub Vec<TokenId>);
+
+impl Docs {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Text of docs without `///` or `//!`, a line for every comment
+    pub fn text(&self, spans: &[lexer::Span], source: &str) -> String {
+        let lines: Vec<_> = self
+            .0
+            .iter()
+            .map(|id| {
+                let comment = spans[id.as_index()].lexeme(source);
+                // Skip `///` or `//!` and a single space after it
+                let line = comment[3..].trim_end_matches(['\n', '\r']);
+                line.strip_prefix(' ').unwrap_or(line)
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
+
+impl Parse for Docs {
+    type Parsed = Self;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ()> {
+        Ok(Self(parse_comments(parser, Kind::Docs)))
+    }
+}
+
+/// Inner docs of file: `//! docs`
+#[derive(Debug, Clone, Copy)]
+pub struct InnerDocs;
+
+impl Parse for InnerDocs {
+    type Parsed = Docs;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Docs, ()> {
+        Ok(Docs(parse_comments(parser, Kind::InnerDocs)))
+    }
+}
+
+fn parse_comments(parser: &mut Parser<'_>, kind: Kind) -> Vec<TokenId> {
+    let mut comments = Vec::new();
+    while let Some(id) = parser.stream.maybe(kind) {
+        comments.push(id);
+    }
+    comments
+}
@@ -0,0 +1,88 @@
+use crate::{
+    lexer::{Kind, TokenId},
+    parser::{
+        BlockExt, BlockStmt, Docs, DocsExt, ParamExt, Parse, Parser, Span, TypePathExpr,
+        TypePathExt, Vis,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct FnDecl {
+    pub span: Span,
+    pub docs: Docs,
+    pub vis: Vis,
+    pub name: TokenId,
+    pub params: Vec<Param>,
+    pub ret: Option<TypePathExpr>,
+    pub block: BlockStmt,
+}
+
+impl Parse for FnDecl {
+    type Parsed = Self;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ()> {
+        let docs = parser.parse_docs()?;
+        let start = parser.stream.current_id();
+        let vis = match parser.stream.maybe(Kind![pub]) {
+            Some(_) => Vis::Public,
+            None => Vis::Private,
+        };
+        parser.expect_item(Kind![fn])?;
+        let name = parser.expect_item(Kind::Ident)?;
+
+        parser.expect_item(Kind!['('])?;
+        let mut params = Vec::new();
+        if parser.stream.maybe(Kind![')']).is_none() {
+            loop {
+                params.push(parser.parse_param()?);
+                if parser.stream.maybe(Kind![,]).is_none() {
+                    parser.expect_item(Kind![')'])?;
+                    break;
+                }
+            }
+        }
+
+        let ret = match parser.stream.maybe(Kind![->]) {
+            Some(_) => Some(parser.parse_type_path()?),
+            None => None,
+        };
+
+        let block = parser.parse_block()?;
+        let end = parser.stream.current_id();
+
+        Ok(Self {
+            span: Span::new(start, end),
+            docs,
+            vis,
+            name,
+            params,
+            ret,
+            block,
+        })
+    }
+}
+
+/// Function parameter like `health: i32`
+#[derive(Debug, Clone)]
+pub struct Param {
+    pub span: Span,
+    pub name: TokenId,
+    pub type_path: TypePathExpr,
+}
+
+impl Parse for Param {
+    type Parsed = Self;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ()> {
+        let name = parser.expect_item(Kind::Ident)?;
+        parser.expect_item(Kind![:])?;
+        let type_path = parser.parse_type_path()?;
+        Ok(Self {
+            span: Span::new(name, parser.stream.current_id()),
+            name,
+            type_path,
+        })
+    }
+}
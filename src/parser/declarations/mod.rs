@@ -0,0 +1,59 @@
+use crate::lexer::Kind;
+
+use super::{
+    DocsExt, ExpectedItem, FnExt, Item, ItemSequence, Parse, Parser, Span, VarStmt, VariableExt,
+};
+
+mod docs;
+pub use docs::*;
+
+mod fn_decl;
+pub use fn_decl::*;
+
+mod program;
+pub use program::*;
+
+#[derive(Debug, Clone)]
+pub enum DeclKind {
+    Const(VarStmt),
+    Fn(FnDecl),
+}
+
+impl DeclKind {
+    /// Tokens of declaration without its docs
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Const(var) => var.span,
+            Self::Fn(fn_decl) => fn_decl.span,
+        }
+    }
+}
+
+impl Parse for DeclKind {
+    type Parsed = Self;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
+        let token = parser.with_frame(|parser| {
+            let _ = parser.parse_docs();
+            parser.stream.maybe(Kind![pub]);
+            parser.stream.first()
+        });
+
+        match token.kind {
+            Kind![fn] => Ok(Self::Fn(parser.parse_fn()?)),
+            Kind![const] => {
+                let var = parser.parse_variable()?;
+                parser.expect_item(Kind![;])?;
+                Ok(Self::Const(var))
+            }
+            _ => {
+                parser.push_error(ExpectedItem::here(
+                    ItemSequence::Decl,
+                    Item::from_token(token),
+                ));
+                Err(())
+            }
+        }
+    }
+}
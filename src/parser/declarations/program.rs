@@ -0,0 +1,52 @@
+use crate::{
+    lexer::Kind,
+    parser::{DeclarationExt, InnerDocsExt, Item, Parse, ParseError, Parser, Span},
+};
+
+use super::{DeclKind, Docs};
+
+/// Whole source file: `program = inner_docs (declaration)*`
+#[derive(Debug, Clone)]
+pub struct Program {
+    /// All tokens of file up to [Kind::Eof]
+    pub span: Span,
+    pub docs: Docs,
+    pub decls: Vec<DeclKind>,
+}
+
+impl Parse for Program {
+    type Parsed = Self;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ()> {
+        let start = parser.stream.current_id();
+        let docs = parser.parse_inner_docs()?;
+        let mut decls = Vec::new();
+        while !matches!(parser.stream.first().kind, Kind::Eof) {
+            let error_count = parser.errors.len();
+            match parser.parse_declaration() {
+                Ok(decl) => decls.push(decl),
+                Err(_) => {
+                    if parser.errors.len() == error_count {
+                        let found = Item::from_token(parser.stream.first());
+                        parser.push_error(ParseError::Unexpected(found));
+                    }
+                    // Skip until something that can start a declaration
+                    parser.stream.skip();
+                    while !matches!(
+                        parser.stream.first().kind,
+                        Kind::Docs | Kind![pub] | Kind![fn] | Kind![const] | Kind::Eof
+                    ) {
+                        parser.stream.skip();
+                    }
+                    parser.push_recovered(error_count);
+                }
+            }
+        }
+        Ok(Self {
+            span: Span::new(start, parser.stream.current_id()),
+            docs,
+            decls,
+        })
+    }
+}
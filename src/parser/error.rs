@@ -1,4 +1,7 @@
-use crate::lexer::{self, AssignKind, BinaryKind, Delimiter, Keyword};
+use crate::{
+    diagnostic::{Applicability, Code},
+    lexer::{self, AssignKind, BinaryKind, Delimiter, Keyword, OverflowMode, TokenId},
+};
 
 use super::{Span, Token};
 
@@ -12,10 +15,25 @@ pub enum ParseError {
 }
 
 impl ParseError {
+    pub const fn code(&self) -> Code {
+        match self {
+            Self::Syntax(kind) => kind.code(),
+            Self::Expected(expected) => expected.expected.code(),
+            Self::Unexpected(_) => Code("P0002"),
+        }
+    }
+
+    pub fn fix(&self) -> Option<&TokenFix> {
+        match self {
+            Self::Expected(expected) => expected.fix.as_ref(),
+            Self::Syntax(_) | Self::Unexpected(_) => None,
+        }
+    }
+
     pub fn span(&self) -> Span {
         match self {
             Self::Syntax(kind) => match kind {
-                SyntaxError::UnvalidAssignment { span } => *span,
+                SyntaxError::UnvalidAssignment { span } | SyntaxError::EmptyHole { span } => *span,
             },
             Self::Expected(item) => item.span,
             Self::Unexpected(item) => item.span,
@@ -29,6 +47,10 @@ pub(crate) enum ItemSequence {
     Unary,
     Assign,
     StmtWithReturnValue,
+    Decl,
+    Range,
+    /// End of statement: `;` or `}` of block
+    StmtEnd,
 }
 
 impl ItemSequence {
@@ -38,8 +60,26 @@ impl ItemSequence {
             Self::Unary => &[ItemKind::Not, ItemKind::Binary(Some(BinaryKind::Minus))],
             Self::Assign => &[ItemKind::Assign(None)],
             Self::StmtWithReturnValue => &[ItemKind::Expr, ItemKind::BlockStmt, ItemKind::IfStmt],
+            Self::Decl => &[ItemKind::Fn, ItemKind::Keyword(Keyword::Const)],
+            Self::Range => &[ItemKind::DotDot, ItemKind::DotDotEq],
+            Self::StmtEnd => &[ItemKind::Semi, ItemKind::CloseDelim(Delimiter::Brace)],
         }
     }
+
+    /// Common cases have their own codes, the rest are `P0001`
+    pub const fn code(&self) -> Code {
+        Code(match self {
+            Self::Single(ItemKind::Semi) | Self::StmtEnd => "P0003",
+            Self::Single(ItemKind::CloseDelim(_)) => "P0004",
+            Self::Single(ItemKind::Expr) | Self::Unary | Self::StmtWithReturnValue => "P0005",
+            Self::Single(ItemKind::Ident) => "P0006",
+            Self::Decl => "P0007",
+            Self::Range => "P0008",
+            // Only variables expect exactly `=`
+            Self::Single(ItemKind::Assign(Some(AssignKind::Eq))) => "P0009",
+            Self::Single(_) | Self::Assign => "P0001",
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -55,6 +95,7 @@ pub struct ExpectedItem {
     pub expected: ItemSequence,
     pub found: ItemKind,
     pub span: Span,
+    pub fix: Option<TokenFix>,
 }
 
 impl ExpectedItem {
@@ -64,9 +105,15 @@ impl ExpectedItem {
             expected,
             found: found.kind,
             span: found.span,
+            fix: None,
         }
     }
 
+    pub fn with_fix(mut self, fix: TokenFix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
     #[inline]
     pub fn here(expected: ItemSequence, found: Item) -> Self {
         Self::new(Place::Here, expected, found)
@@ -95,6 +142,7 @@ impl core::fmt::Display for ParseError {
                 expected,
                 found,
                 span: _,
+                fix: _,
             }) => {
                 if matches!(found, ItemKind::Eof) {
                     write!(f, "Expected ")?;
@@ -118,11 +166,59 @@ impl core::fmt::Display for ParseError {
     }
 }
 
+/// Fix of parse error in tokens, byte edit is made from token spans
+#[derive(Debug, Clone)]
+pub struct TokenFix {
+    pub edit: TokenEdit,
+    pub text: &'static str,
+    pub applicability: Applicability,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TokenEdit {
+    InsertAfter(TokenId),
+    Replace(TokenId),
+}
+
+impl TokenFix {
+    pub fn insert_after(id: TokenId, text: &'static str) -> Self {
+        Self {
+            edit: TokenEdit::InsertAfter(id),
+            text,
+            applicability: Applicability::MachineApplicable,
+        }
+    }
+
+    pub fn replace(id: TokenId, text: &'static str, applicability: Applicability) -> Self {
+        Self {
+            edit: TokenEdit::Replace(id),
+            text,
+            applicability,
+        }
+    }
+}
+
 // endregion: ----- ParseError -----
 
 #[derive(Debug, Clone)]
 pub enum SyntaxError {
-    UnvalidAssignment { span: Span },
+    UnvalidAssignment {
+        span: Span,
+    },
+    /// `{}` in interpolated string, `span` is string part after it
+    EmptyHole {
+        span: Span,
+    },
+}
+
+impl SyntaxError {
+    /// Syntax errors start from `P0010`
+    pub const fn code(&self) -> Code {
+        Code(match self {
+            Self::UnvalidAssignment { .. } => "P0010",
+            Self::EmptyHole { .. } => "P0011",
+        })
+    }
 }
 
 impl core::error::Error for SyntaxError {}
@@ -131,6 +227,7 @@ impl core::fmt::Display for SyntaxError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::UnvalidAssignment { .. } => write!(f, "unvalid assignment"),
+            Self::EmptyHole { .. } => write!(f, "expected expression inside `{{}}`"),
         }
     }
 }
@@ -203,6 +300,7 @@ pub enum ItemKind {
     Literal,
     Binary(Option<BinaryKind>),
     Assign(Option<AssignKind>),
+    OverflowAssign(AssignKind, OverflowMode),
     OpenDelim(Delimiter),
     CloseDelim(Delimiter),
     Unknown,
@@ -223,7 +321,7 @@ pub enum ItemKind {
 impl ItemKind {
     pub const fn from_lexer_kind(kind: lexer::Kind) -> Self {
         match kind {
-            lexer::Kind::Docs => Self::Docs,
+            lexer::Kind::Docs | lexer::Kind::InnerDocs => Self::Docs,
             lexer::Kind::Not => Self::Not,
             lexer::Kind::Tilde => Self::Tilde,
             lexer::Kind::At => Self::At,
@@ -242,12 +340,14 @@ impl ItemKind {
             lexer::Kind::Question => Self::Question,
             lexer::Kind::Ident => Self::Ident,
             lexer::Kind::Keyword(keyword) => Self::Keyword(keyword),
-            lexer::Kind::Literal(_) => Self::Literal,
+            lexer::Kind::Literal(_) | lexer::Kind::StrPart(_) => Self::Literal,
             lexer::Kind::Binary(kind) => Self::Binary(Some(kind)),
-            lexer::Kind::Assign(kind) => Self::Assign(Some(kind)),
+            lexer::Kind::Assign(kind, None) => Self::Assign(Some(kind)),
+            lexer::Kind::Assign(kind, Some(mode)) => Self::OverflowAssign(kind, mode),
             lexer::Kind::OpenDelim(delimiter) => Self::OpenDelim(delimiter),
             lexer::Kind::CloseDelim(delimiter) => Self::CloseDelim(delimiter),
-            lexer::Kind::Unknown => Self::Unknown,
+            // Parser gets tokens without trivia
+            lexer::Kind::Trivia(_) | lexer::Kind::Unknown => Self::Unknown,
             lexer::Kind::Eof => Self::Eof,
         }
     }
@@ -301,6 +401,9 @@ impl core::fmt::Display for ItemKind {
                 Some(kind) => write!(f, "{kind}"),
                 None => write!(f, "assign operator"),
             },
+            Self::OverflowAssign(kind, mode) => {
+                write!(f, "{}", lexer::Kind::Assign(*kind, Some(*mode)))
+            }
             Self::OpenDelim(delim) => {
                 let c = match delim {
                     Delimiter::Paren => '(',
@@ -1,8 +1,8 @@
 use crate::{
-    lexer::{self, Kind, BinaryKind},
+    lexer::{self, BinaryKind, Kind},
     parser::{
         BinAndExt, BinOrExt, ComparisonExt, EqualityExt, FactorExt, LogicAndExt, Parse, Parser,
-        PowerExt, TermExt, UnaryExt,
+        PowerExt, ShiftExt, Span, TermExt, UnaryExt,
     },
 };
 
@@ -10,14 +10,15 @@ use super::ExprKind;
 
 #[derive(Debug, Clone)]
 pub struct BinaryExpr {
+    pub span: Span,
     pub op: BinaryKind,
-    pub rhs: Box<ExprKind>,
     pub lhs: Box<ExprKind>,
+    pub rhs: Box<ExprKind>,
 }
 
 impl BinaryExpr {
-    pub const fn new(op: BinaryKind, rhs: Box<ExprKind>, lhs: Box<ExprKind>) -> Self {
-        Self { op, rhs, lhs }
+    pub const fn new(span: Span, op: BinaryKind, lhs: Box<ExprKind>, rhs: Box<ExprKind>) -> Self {
+        Self { span, op, lhs, rhs }
     }
 }
 
@@ -61,10 +62,16 @@ binary! {
 
 binary! {
     name: Comparison,
-    fun: Parser::parse_term,
+    fun: Parser::parse_shift,
     operators: [Kind![<], Kind![>], Kind![<=], Kind![>=]],
 }
 
+binary! {
+    name: Shift,
+    fun: Parser::parse_term,
+    operators: [Kind![<<], Kind![>>]],
+}
+
 binary! {
     name: Term,
     fun: Parser::parse_factor,
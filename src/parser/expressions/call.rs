@@ -1,15 +1,52 @@
-use crate::parser::{Parse, Parser, PathExpr, ValueExt};
+use crate::{
+    lexer::{Delimiter, Kind, TokenId},
+    parser::{
+        ExpectedItem, ExpressionExt, Item, ItemKind, ItemSequence, Parse, Parser, PathExpr, Span,
+        ValueExt,
+    },
+};
 
 use super::ExprKind;
 
+/// Call like `callee(arg, arg)`
 #[derive(Debug, Clone)]
 pub struct CallExpr {
-    pub path: PathExpr,
+    pub span: Span,
+    pub callee: Box<ExprKind>,
+    pub args: Vec<ExprKind>,
 }
 
 impl CallExpr {
-    pub fn new(path: PathExpr) -> Self {
-        Self { path }
+    pub fn new(span: Span, callee: Box<ExprKind>, args: Vec<ExprKind>) -> Self {
+        Self { span, callee, args }
+    }
+}
+
+/// Index like `expr[index]` or slice like `expr[1..3]`
+#[derive(Debug, Clone)]
+pub struct IndexExpr {
+    pub span: Span,
+    pub expr: Box<ExprKind>,
+    pub index: Box<ExprKind>,
+}
+
+impl IndexExpr {
+    pub fn new(span: Span, expr: Box<ExprKind>, index: Box<ExprKind>) -> Self {
+        Self { span, expr, index }
+    }
+}
+
+/// Field access on the result of expression like `call().field`
+#[derive(Debug, Clone)]
+pub struct FieldExpr {
+    pub span: Span,
+    pub expr: Box<ExprKind>,
+    pub name: TokenId,
+}
+
+impl FieldExpr {
+    pub fn new(span: Span, expr: Box<ExprKind>, name: TokenId) -> Self {
+        Self { span, expr, name }
     }
 }
 
@@ -18,15 +55,79 @@ impl Parse for CallExpr {
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<ExprKind, ()> {
+        let start = parser.stream.current_id();
         let mut snapshot = parser.clone();
-        let call = match snapshot.parse::<PathExpr>() {
+        let mut call = match snapshot.parse::<PathExpr>() {
             Ok(path) => {
                 *parser = snapshot;
                 ExprKind::Path(path)
             }
             Err(_) => parser.parse_value()?,
         };
+
+        loop {
+            let token = parser.stream.first();
+            call = match token.kind {
+                Kind!['('] => {
+                    parser.stream.skip();
+                    let args = parse_args(parser)?;
+                    let span = Span::new(start, parser.stream.current_id());
+                    ExprKind::Call(CallExpr::new(span, Box::new(call), args))
+                }
+                Kind![.] => {
+                    parser.stream.skip();
+                    let name = parser.expect_item(Kind::Ident)?;
+                    let span = Span::new(start, parser.stream.current_id());
+                    ExprKind::Field(FieldExpr::new(span, Box::new(call), name))
+                }
+                Kind!['['] => {
+                    parser.stream.skip();
+                    let index = parser.parse_expr_or_error(Parser::parse_expression);
+                    parser.expect_item(Kind![']'])?;
+                    let span = Span::new(start, parser.stream.current_id());
+                    ExprKind::Index(IndexExpr::new(span, Box::new(call), Box::new(index)))
+                }
+                _ => break,
+            };
+        }
         Ok(call)
     }
 }
 
+/// Parse `args ::= expr ("," expr)*` and closing `)`
+fn parse_args(parser: &mut Parser<'_>) -> Result<Vec<ExprKind>, ()> {
+    let mut args = Vec::new();
+    if parser.stream.maybe(Kind![')']).is_some() {
+        return Ok(args);
+    }
+
+    loop {
+        args.push(parser.parse_expr_or_error(Parser::parse_expression));
+        let token = parser.stream.first();
+        match token.kind {
+            Kind![')'] => {
+                parser.stream.skip();
+                break;
+            }
+            Kind![,] => {
+                parser.stream.skip();
+                let token = parser.stream.first();
+                if let Kind![')'] = token.kind {
+                    // trailing comma: `call(a, b,)`
+                    let expected = ItemSequence::Single(ItemKind::Expr);
+                    parser.push_error(ExpectedItem::here(expected, Item::from_token(token)));
+                    parser.stream.skip();
+                    break;
+                }
+            }
+            _ => {
+                let kind = ItemKind::CloseDelim(Delimiter::Paren);
+                let expected = ItemSequence::Single(kind);
+                let found = Item::from_token(token);
+                parser.push_error(ExpectedItem::here(expected, found));
+                break;
+            }
+        }
+    }
+    Ok(args)
+}
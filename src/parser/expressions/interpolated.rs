@@ -0,0 +1,63 @@
+use crate::{
+    lexer::{Delimiter, Kind, StrPart, TokenId},
+    parser::{
+        ExpectedItem, ExpressionExt, Item, ItemKind, ItemSequence, Parse, ParseError, Parser, Span,
+        SyntaxError,
+    },
+};
+
+use super::ExprKind;
+
+/// Interpolated string like `"hp: {health}/{max}!"`
+#[derive(Debug, Clone)]
+pub struct InterpolatedExpr {
+    pub span: Span,
+    pub parts: Vec<InterpolatedPart>,
+}
+
+#[derive(Debug, Clone)]
+pub enum InterpolatedPart {
+    /// String part token: `"hp: {`, `}/{` or `}!"`
+    Str(TokenId),
+    /// Expression inside hole
+    Expr(ExprKind),
+}
+
+impl Parse for InterpolatedExpr {
+    type Parsed = Self;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self, ()> {
+        let head = parser.expect_item(Kind::StrPart(StrPart::Head))?;
+        let mut parts = vec![InterpolatedPart::Str(head)];
+        loop {
+            let token = parser.stream.first();
+            if let Kind::StrPart(StrPart::Middle | StrPart::Tail) = token.kind {
+                // Empty hole `{}`, string part right after the previous one
+                let span = Span::dot(token.id);
+                parser.push_error(ParseError::Syntax(SyntaxError::EmptyHole { span }));
+            } else {
+                let expr = parser.parse_expression()?;
+                parts.push(InterpolatedPart::Expr(expr));
+            }
+            let token = parser.stream.first();
+            match token.kind {
+                Kind::StrPart(StrPart::Middle) => {
+                    parts.push(InterpolatedPart::Str(parser.stream.next_id()));
+                }
+                Kind::StrPart(StrPart::Tail) => {
+                    parts.push(InterpolatedPart::Str(parser.stream.next_id()));
+                    break;
+                }
+                _ => {
+                    // Hole is closed by `}`
+                    let expected = ItemSequence::Single(ItemKind::CloseDelim(Delimiter::Brace));
+                    parser.push_error(ExpectedItem::after(expected, Item::from_token(token)));
+                    return Err(());
+                }
+            }
+        }
+        let span = Span::new(head, parser.stream.current_id());
+        Ok(Self { span, parts })
+    }
+}
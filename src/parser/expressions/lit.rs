@@ -1,11 +1,17 @@
 use crate::{
     lexer::{Kind, TokenId},
-    parser::{Item, Parse, ParseError, Parser},
+    parser::{Item, Parse, ParseError, Parser, Span},
 };
 
 #[derive(Debug, Clone, Copy)]
 pub struct LitExpr(pub TokenId);
 
+impl LitExpr {
+    pub fn span(&self) -> Span {
+        Span::token(self.0)
+    }
+}
+
 impl Parse for LitExpr {
     type Parsed = Self;
     type Error = ();
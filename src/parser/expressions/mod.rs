@@ -1,29 +1,40 @@
-use crate::{lexer::TokenId, parser::LogicOrExt};
+use crate::{lexer::TokenId, parser::RangeExt};
 
 mod binary;
 mod call;
+mod interpolated;
 mod lit;
 mod path;
+mod range;
 mod type_path;
 mod unary;
 mod value;
 
 pub use binary::*;
 pub use call::*;
+pub use interpolated::*;
 pub use lit::*;
 pub use path::*;
+pub use range::*;
 pub use type_path::*;
 pub use unary::*;
 pub use value::*;
 
-use super::{AssignStmt, BlockStmt, IfStmt, Parse, Parser, VarStmt};
+use super::{AssignStmt, BlockStmt, IfStmt, Parse, Parser, Span, VarStmt};
 
 #[derive(Debug, Clone)]
 pub enum ExprKind {
     Lit(LitExpr),
+    Interpolated(InterpolatedExpr),
     Path(PathExpr),
     Unary(UnaryExpr),
     Binary(BinaryExpr),
+    Call(CallExpr),
+    Field(FieldExpr),
+    Index(IndexExpr),
+    Range(RangeExpr),
+    /// Tokens of expression which failed to parse, its error is already reported
+    Error(Span),
     // TODO: Move all (stmt) in Expr
     // For `if stmt { (stmts)* } else { (stmts)* } * if stmt { (stmts)* } else { (stmts)* }`
     // It just cool XD
@@ -33,12 +44,30 @@ pub enum ExprKind {
     // If(IfStmt),
 }
 
+impl ExprKind {
+    /// Tokens of expression, parentheses around it are not included
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Lit(lit) => lit.span(),
+            Self::Interpolated(interpolated) => interpolated.span,
+            Self::Path(path) => path.span(),
+            Self::Unary(unary) => unary.span,
+            Self::Binary(binary) => binary.span,
+            Self::Call(call) => call.span,
+            Self::Field(field) => field.span,
+            Self::Index(index) => index.span,
+            Self::Range(range) => range.span,
+            Self::Error(span) => *span,
+        }
+    }
+}
+
 impl Parse for ExprKind {
     type Parsed = Self;
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
-        parser.parse_logic_or()
+        parser.parse_range()
     }
 }
 
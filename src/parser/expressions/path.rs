@@ -1,6 +1,6 @@
 use crate::{
     lexer::{Kind, TokenId},
-    parser::{ExpectedItem, Item, ItemKind, ItemSequence, Parse, Parser, TypePathExt},
+    parser::{ExpectedItem, Item, ItemKind, ItemSequence, Parse, Parser, Span, TypePathExt},
 };
 
 #[derive(Debug, Clone)]
@@ -24,6 +24,12 @@ impl PathExpr {
             var_len,
         }
     }
+
+    /// Tokens from the first to the last ident, leading `::` is not included
+    pub fn span(&self) -> Span {
+        let len = 1 + 2 * (self.mod_len as u32 + self.var_len as u32);
+        Span::new(self.start, TokenId(self.start.0 + len))
+    }
 }
 
 impl Parse for PathExpr {
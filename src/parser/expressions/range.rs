@@ -0,0 +1,106 @@
+use crate::{
+    lexer::{Kind, StrPart},
+    parser::{ExpectedItem, Item, ItemKind, ItemSequence, LogicOrExt, Parse, Parser, Span},
+};
+
+use super::ExprKind;
+
+/// Range like `a..b`, `a..=b`, `..b` or `a..`
+///
+/// Has the lowest precedence: `a + 1..b * 2` is `(a + 1)..(b * 2)`
+#[derive(Debug, Clone)]
+pub struct RangeExpr {
+    pub span: Span,
+    pub kind: RangeKind,
+    pub start: Option<Box<ExprKind>>,
+    pub end: Option<Box<ExprKind>>,
+}
+
+impl RangeExpr {
+    pub const fn new(
+        span: Span,
+        kind: RangeKind,
+        start: Option<Box<ExprKind>>,
+        end: Option<Box<ExprKind>>,
+    ) -> Self {
+        Self {
+            span,
+            kind,
+            start,
+            end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RangeKind {
+    /// `..`
+    Exclusive,
+    /// `..=`
+    Inclusive,
+}
+
+impl Parse for RangeExpr {
+    type Parsed = ExprKind;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<ExprKind, ()> {
+        let first = parser.stream.current_id();
+        let start = match parser.stream.first().kind {
+            Kind![..] | Kind![..=] | Kind![...] => None,
+            _ => Some(parser.parse_logic_or()?),
+        };
+
+        let token = parser.stream.first();
+        let kind = match token.kind {
+            Kind![..] => RangeKind::Exclusive,
+            Kind![..=] => RangeKind::Inclusive,
+            Kind![...] => {
+                // `...` is not a range operator, but user most likely meant `..=`
+                parser.push_error(ExpectedItem::here(
+                    ItemSequence::Range,
+                    Item::from_token(token),
+                ));
+                RangeKind::Inclusive
+            }
+            // `start` always exists here
+            _ => return start.ok_or(()),
+        };
+        parser.stream.skip();
+
+        let token = parser.stream.first();
+        let end = if can_begin_expr(token.kind) {
+            Some(Box::new(parser.parse_expr_or_error(Parser::parse_logic_or)))
+        } else {
+            if let RangeKind::Inclusive = kind {
+                // `a..=` must have end
+                parser.push_error(ExpectedItem::here(
+                    ItemSequence::Single(ItemKind::Expr),
+                    Item::from_token(token),
+                ));
+            }
+            None
+        };
+
+        Ok(ExprKind::Range(RangeExpr::new(
+            Span::new(first, parser.stream.current_id()),
+            kind,
+            start.map(Box::new),
+            end,
+        )))
+    }
+}
+
+/// Check if token can start operand of range: `a..` followed by `{`, `)` or `;` has no end
+fn can_begin_expr(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::Ident
+            | Kind::Literal(_)
+            | Kind::StrPart(StrPart::Head)
+            | Kind![::]
+            | Kind!['(']
+            | Kind![!]
+            | Kind![-]
+    )
+}
@@ -1,6 +1,6 @@
 use crate::{
     lexer::{Kind, TokenId},
-    parser::{ExpectedItem, Item, ItemKind, ItemSequence, Parse, Parser},
+    parser::{ExpectedItem, Item, ItemKind, ItemSequence, Parse, Parser, Span},
 };
 
 #[derive(Debug, Clone)]
@@ -17,6 +17,11 @@ impl TypePathExpr {
     pub const fn new(start: TokenId, len: u16) -> Self {
         Self { start, len }
     }
+
+    /// Tokens from the first to the last ident, leading `::` is not included
+    pub fn span(&self) -> Span {
+        Span::new(self.start, TokenId(self.start.0 + 1 + 2 * self.len as u32))
+    }
 }
 
 impl Parse for TypePathExpr {
@@ -1,5 +1,5 @@
 use crate::{
-    lexer::{Keyword, Kind},
+    lexer::{Keyword, Kind, StrPart},
     parser::{
         CallExt, ExpectedItem, Item, ItemKind, ItemSequence, Parse, ParseError, Parser, Span,
     },
@@ -9,13 +9,18 @@ use super::ExprKind;
 
 #[derive(Debug, Clone)]
 pub struct UnaryExpr {
+    pub span: Span,
     pub operators: Vec<UnaryKind>,
     pub expr: Box<ExprKind>,
 }
 
 impl UnaryExpr {
-    pub const fn new(operators: Vec<UnaryKind>, expr: Box<ExprKind>) -> Self {
-        Self { operators, expr }
+    pub const fn new(span: Span, operators: Vec<UnaryKind>, expr: Box<ExprKind>) -> Self {
+        Self {
+            span,
+            operators,
+            expr,
+        }
     }
 }
 
@@ -24,7 +29,9 @@ impl Parse for UnaryExpr {
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<ExprKind, ()> {
+        let start = parser.stream.current_id();
         let mut operators = Vec::new();
+        let mut reported = false;
         loop {
             let token = parser.stream.first();
             if matches!(token.kind, Kind::Eof) {
@@ -36,7 +43,12 @@ impl Parse for UnaryExpr {
                 Kind![!] => UnaryKind::Not,
                 Kind![-] => UnaryKind::Minus,
                 _ => match token.kind {
-                    Kind!['('] | Kind!['{'] | Kind!['}'] | Kind::Ident | Kind::Literal(_) => break,
+                    Kind!['(']
+                    | Kind!['{']
+                    | Kind!['}']
+                    | Kind::Ident
+                    | Kind::Literal(_)
+                    | Kind::StrPart(StrPart::Head) => break,
                     Kind::Keyword(keyword) => {
                         let kind = match keyword {
                             Keyword::Pub => ItemKind::Stmt,
@@ -47,6 +59,7 @@ impl Parse for UnaryExpr {
                             Keyword::Else => ItemKind::Stmt,
                             Keyword::In => ItemKind::Keyword(Keyword::In),
                             Keyword::Fn => ItemKind::Fn,
+                            Keyword::Break | Keyword::Continue | Keyword::Return => ItemKind::Stmt,
                             Keyword::If | Keyword::While | Keyword::For => break,
                         };
                         parser.push_error(ExpectedItem::here(
@@ -55,11 +68,21 @@ impl Parse for UnaryExpr {
                         ));
                         break;
                     }
+                    // End of statement or enclosing expression, leave it to their recovery
+                    Kind![;] | Kind![')'] | Kind![']'] | Kind![,] => {
+                        if !reported {
+                            let found = Item::from_token(token);
+                            parser.push_error(ExpectedItem::here(ItemSequence::Unary, found));
+                        }
+                        return Err(());
+                    }
+                    // Skip unexpected tokens, only the first is reported
                     _ => {
-                        parser.push_error(ExpectedItem::here(
-                            ItemSequence::Unary,
-                            Item::from_token(token),
-                        ));
+                        if !reported {
+                            let found = Item::from_token(token);
+                            parser.push_error(ExpectedItem::here(ItemSequence::Unary, found));
+                            reported = true;
+                        }
                         parser.stream.skip();
                         continue;
                     }
@@ -71,7 +94,8 @@ impl Parse for UnaryExpr {
 
         let expr = parser.parse_call()?;
         Ok(if !operators.is_empty() {
-            let unary = UnaryExpr::new(operators, Box::new(expr));
+            let span = Span::new(start, parser.stream.current_id());
+            let unary = UnaryExpr::new(span, operators, Box::new(expr));
             ExprKind::Unary(unary)
         } else {
             expr
@@ -1,8 +1,8 @@
 use crate::{
-    lexer::{Delimiter, Kind},
+    lexer::{Delimiter, Kind, StrPart},
     parser::{
-        BlockExt, ExpectedItem, ExpressionExt, IfExt, Item, ItemKind, ItemSequence, LitExt, Parse,
-        Parser, StmtKind,
+        BlockExt, ExpectedItem, ExpressionExt, IfExt, InterpolatedExt, Item, ItemKind,
+        ItemSequence, LitExt, Parse, Parser, StmtKind, TokenFix,
     },
 };
 
@@ -29,12 +29,17 @@ impl Parse for ValueExpr {
                         let kind = ItemKind::CloseDelim(Delimiter::Paren);
                         let expected = ItemSequence::Single(kind);
                         let found = Item::from_token(token);
-                        parser.push_error(ExpectedItem::here(expected, found));
+                        let mut error = ExpectedItem::here(expected, found);
+                        if let Some(last) = parser.stream.previous_id() {
+                            error = error.with_fix(TokenFix::insert_after(last, ")"));
+                        }
+                        parser.push_error(error);
                     }
                 }
                 expr
             }
             Kind::Literal(_) => ExprKind::Lit(parser.parse_lit()?),
+            Kind::StrPart(StrPart::Head) => ExprKind::Interpolated(parser.parse_interpolated()?),
             _ => return Err(()),
         };
         Ok(value)
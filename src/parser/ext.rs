@@ -1,7 +1,9 @@
 use super::{
-    AssignStmt, BinAndExpr, BinOrExpr, BlockStmt, CallExpr, ComparisonExpr, EqualityExpr, ExprKind,
-    FactorExpr, IfStmt, LitExpr, LogicAndExpr, LogicOrExpr, ParseStmtError, PathExpr, PowerExpr,
-    StmtKind, TermExpr, TypePathExpr, UnaryExpr, ValueExpr, VarStmt,
+    AssignStmt, BinAndExpr, BinOrExpr, BlockStmt, CallExpr, ComparisonExpr, DeclKind, Docs,
+    EqualityExpr, ExprKind, FactorExpr, FnDecl, ForStmt, IfStmt, InnerDocs, InterpolatedExpr,
+    LitExpr, LogicAndExpr, LogicOrExpr, Param, ParseStmtError, PathExpr, PowerExpr, Program,
+    RangeExpr, ReturnStmt, ShiftExpr, StmtKind, TermExpr, TypePathExpr, UnaryExpr, ValueExpr,
+    VarStmt, WhileStmt,
 };
 
 macro_rules! parser_ext {
@@ -26,6 +28,7 @@ macro_rules! parser_ext {
 parser_ext! {
     // Exprs
     Lit(LitExpr)               -> Result<LitExpr, ()>;
+    Interpolated(InterpolatedExpr) -> Result<InterpolatedExpr, ()>;
     TypePath(TypePathExpr)     -> Result<TypePathExpr, ()>;
     Path(PathExpr)             -> Result<PathExpr, ()>;
     Value(ValueExpr)           -> Result<ExprKind, ()>;
@@ -37,16 +40,28 @@ parser_ext! {
     BinOr(BinOrExpr)           -> Result<ExprKind, ()>;
     Factor(FactorExpr)         -> Result<ExprKind, ()>;
     Term(TermExpr)             -> Result<ExprKind, ()>;
+    Shift(ShiftExpr)           -> Result<ExprKind, ()>;
     Comparison(ComparisonExpr) -> Result<ExprKind, ()>;
     Equality(EqualityExpr)     -> Result<ExprKind, ()>;
     LogicAnd(LogicAndExpr)     -> Result<ExprKind, ()>;
     LogicOr(LogicOrExpr)       -> Result<ExprKind, ()>;
+    Range(RangeExpr)           -> Result<ExprKind, ()>;
     Expression(ExprKind)       -> Result<ExprKind, ()>;
     // Stmt
     Variable(VarStmt)          -> Result<VarStmt, ()>;
     Assign(AssignStmt)         -> Result<AssignStmt, ()>;
     Block(BlockStmt)           -> Result<BlockStmt, ()>;
     If(IfStmt)                 -> Result<IfStmt, ()>;
+    While(WhileStmt)           -> Result<WhileStmt, ()>;
+    For(ForStmt)               -> Result<ForStmt, ()>;
+    Return(ReturnStmt)         -> Result<ReturnStmt, ()>;
     Statement(StmtKind)        -> Result<StmtKind, ParseStmtError>;
     Statements(Vec<StmtKind>)  -> Result<Vec<StmtKind>, ()>;
+    // Decl
+    Docs(Docs)                 -> Result<Docs, ()>;
+    InnerDocs(InnerDocs)       -> Result<Docs, ()>;
+    Param(Param)               -> Result<Param, ()>;
+    Fn(FnDecl)                 -> Result<FnDecl, ()>;
+    Declaration(DeclKind)      -> Result<DeclKind, ()>;
+    Program(Program)           -> Result<Program, ()>;
 }
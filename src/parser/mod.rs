@@ -1,5 +1,4 @@
-use std::fmt::Debug;
-
+#[cfg(test)]
 use crate::lexer::{self, Kind};
 
 mod declarations;
@@ -34,19 +33,606 @@ pub trait Parse {
 
 #[test]
 fn test() {
-    let path = "main.rw";
-    let source = std::fs::read_to_string(path).unwrap();
-    let (kinds, spans) = tokenize(path, &source);
+    let mut sources = lexer::SourceMap::new();
+    let file = sources.load("main.rw").unwrap();
+    let (kinds, spans) = tokenize(&sources, file);
+    let mut parser = Parser::new(&kinds);
+    let program = parse_with_parser::<Program>(&mut parser, &sources, &spans);
+    assert!(program.is_ok());
+    assert!(parser.errors.is_empty());
+}
+
+#[test]
+fn range() {
+    let source = "a + 1..b * 2";
+    let (kinds, _) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let expr = parser.parse::<ExprKind>();
+    assert!(parser.errors.is_empty());
+    let Ok(ExprKind::Range(range)) = expr else {
+        panic!("expected range, found {expr:?}");
+    };
+    assert!(matches!(range.kind, RangeKind::Exclusive));
+    assert!(matches!(range.start.as_deref(), Some(ExprKind::Binary(_))));
+    assert!(matches!(range.end.as_deref(), Some(ExprKind::Binary(_))));
+
+    // Source, inclusive, has start, has end and the first error
+    let cases = [
+        ("a..=b", true, true, true, None),
+        ("..b", false, false, true, None),
+        ("a..", false, true, false, None),
+        (
+            "a...b",
+            true,
+            true,
+            true,
+            Some("Found `...`, expected `..` or `..=`"),
+        ),
+        ("a..=", true, true, false, Some("Expected `expression`")),
+    ];
+    for (source, inclusive, start, end, error) in cases {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        let expr = parser.parse::<ExprKind>();
+        let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(messages.first().map(String::as_str), error, "{source}");
+        let Ok(ExprKind::Range(range)) = expr else {
+            panic!("expected range, found {expr:?}");
+        };
+        assert_eq!(
+            matches!(range.kind, RangeKind::Inclusive),
+            inclusive,
+            "{source}"
+        );
+        assert_eq!(range.start.is_some(), start, "{source}");
+        assert_eq!(range.end.is_some(), end, "{source}");
+    }
+}
+
+#[test]
+fn shift() {
+    let source = "1 << 4 + x";
+    let (kinds, _) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let expr = parser.parse::<ExprKind>();
+    assert!(parser.errors.is_empty());
+    let Ok(ExprKind::Binary(shl)) = expr else {
+        panic!("expected binary, found {expr:?}");
+    };
+    assert_eq!(shl.op, lexer::BinaryKind::Shl);
+    assert!(matches!(*shl.lhs, ExprKind::Lit(_)));
+    assert!(matches!(&*shl.rhs, ExprKind::Binary(add) if add.op == lexer::BinaryKind::Plus));
+}
+
+#[test]
+fn overflow_assign() {
+    use lexer::{AssignKind, OverflowMode};
+
+    let cases = [
+        ("z +%= 3;", AssignKind::Plus, OverflowMode::Wrapping),
+        ("z -#= 3;", AssignKind::Minus, OverflowMode::Saturating),
+        ("z *?= 3;", AssignKind::Star, OverflowMode::Checked),
+        ("z /%= 3;", AssignKind::Slash, OverflowMode::Wrapping),
+        ("z <<#= 3;", AssignKind::Shl, OverflowMode::Saturating),
+        ("z >>%= 3;", AssignKind::Shr, OverflowMode::Wrapping),
+    ];
+    for (source, op, overflow) in cases {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        let stmt = parser.parse::<StmtKind>();
+        assert!(parser.errors.is_empty(), "{source}: {:?}", parser.errors);
+        let Ok(StmtKind::Assign(assign)) = stmt else {
+            panic!("expected assignment, found {stmt:?}");
+        };
+        assert_eq!(assign.op, op, "{source}");
+        assert_eq!(assign.overflow, Some(overflow), "{source}");
+    }
+}
+
+#[test]
+fn calls() {
+    let source = "a.b(1)(2).c";
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let expr = parser.parse::<ExprKind>();
+    assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+    let lexeme = |expr: &ExprKind| expr.span().to_lexer_span(&spans).lexeme(source);
+    let Ok(ExprKind::Field(field)) = expr else {
+        panic!("expected field, found {expr:?}");
+    };
+    assert_eq!(spans[field.name.as_index()].lexeme(source), "c");
+    let ExprKind::Call(outer) = &*field.expr else {
+        panic!("expected call, found {:?}", field.expr);
+    };
+    let args: Vec<&str> = outer.args.iter().map(lexeme).collect();
+    assert_eq!(args, ["2"]);
+    let ExprKind::Call(inner) = &*outer.callee else {
+        panic!("expected call, found {:?}", outer.callee);
+    };
+    let args: Vec<&str> = inner.args.iter().map(lexeme).collect();
+    assert_eq!(args, ["1"]);
+    // `a.b` is single path, not field of `a`
+    assert!(matches!(&*inner.callee, ExprKind::Path(path) if path.var_len == 1));
+
+    let source = "a[0]";
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let expr = parser.parse::<ExprKind>();
+    assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+    let Ok(ExprKind::Index(index)) = expr else {
+        panic!("expected index, found {expr:?}");
+    };
+    assert!(matches!(*index.expr, ExprKind::Path(_)));
+    assert!(matches!(*index.index, ExprKind::Lit(_)));
+    assert_eq!(index.span.to_lexer_span(&spans).lexeme(source), "a[0]");
+
+    let cases = [
+        ("f(a, b,)", "Found `)`, expected `expression`"),
+        ("f(a b)", "Found ident, expected `)`"),
+        ("f(a", "Expected `)`"),
+    ];
+    for (source, message) in cases {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        assert!(matches!(parser.parse::<ExprKind>(), Ok(ExprKind::Call(_))));
+        let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(messages, [message], "{source}");
+    }
+}
+
+#[test]
+fn operands() {
+    let source = "a - b - c";
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let Ok(ExprKind::Binary(sub)) = parser.parse::<ExprKind>() else {
+        panic!("expected binary");
+    };
+    // `(a - b) - c`, left operand stays on the left
+    let lexeme = |expr: &ExprKind| expr.span().to_lexer_span(&spans).lexeme(source);
+    assert_eq!(lexeme(&sub.lhs), "a - b");
+    assert_eq!(lexeme(&sub.rhs), "c");
+    let ExprKind::Binary(inner) = &*sub.lhs else {
+        panic!("expected binary, found {:?}", sub.lhs);
+    };
+    assert_eq!(lexeme(&inner.lhs), "a");
+    assert_eq!(lexeme(&inner.rhs), "b");
+}
+
+#[test]
+fn interpolated() {
+    let source = r#""hp: {health}/{max + 1}!""#;
+    let (kinds, _) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let expr = parser.parse::<ExprKind>();
+    assert!(parser.errors.is_empty());
+    let Ok(ExprKind::Interpolated(interpolated)) = expr else {
+        panic!("expected interpolated string, found {expr:?}");
+    };
+    assert!(matches!(
+        interpolated.parts.as_slice(),
+        [
+            InterpolatedPart::Str(_),
+            InterpolatedPart::Expr(ExprKind::Path(_)),
+            InterpolatedPart::Str(_),
+            InterpolatedPart::Expr(ExprKind::Binary(_)),
+            InterpolatedPart::Str(_),
+        ]
+    ));
+
+    let source = r#""{a b}""#;
+    let (kinds, _) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    assert!(parser.parse::<ExprKind>().is_err());
+    assert_eq!(parser.errors.len(), 1);
+
+    // Empty hole is reported, the rest of string is parsed
+    let source = r#""hp: {}/{max}""#;
+    let (kinds, _) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let expr = parser.parse::<ExprKind>();
+    let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["expected expression inside `{}`"]);
+    let Ok(ExprKind::Interpolated(interpolated)) = expr else {
+        panic!("expected interpolated string, found {expr:?}");
+    };
+    assert_eq!(interpolated.parts.len(), 4);
+}
+
+#[test]
+fn docs() {
+    let source = r#"
+//! Game
+//! rules
+/// Max
+const MAX = 1;
+
+///   Heal
+fn heal() {
+    /// Bonus
+    let bonus = 2;
+    /// Nothing
+    bonus
+}
+"#
+    .trim();
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let Ok(program) = parser.parse::<Program>() else {
+        panic!("expected program");
+    };
+    // `/// Nothing` is not attached to anything
+    assert_eq!(parser.errors.len(), 1);
+    assert_eq!(program.docs.text(&spans, source), "Game\nrules");
+    let [DeclKind::Const(max), DeclKind::Fn(heal)] = program.decls.as_slice() else {
+        panic!("expected const and fn, found {:?}", program.decls);
+    };
+    assert_eq!(max.docs.text(&spans, source), "Max");
+    assert_eq!(heal.docs.text(&spans, source), "  Heal");
+    let Some(StmtKind::Var(bonus)) = heal.block.stmts.first() else {
+        panic!("expected variable, found {:?}", heal.block.stmts);
+    };
+    assert_eq!(bonus.docs.text(&spans, source), "Bonus");
+}
+
+#[test]
+fn recovery() {
+    let source = r#"
+fn main() {
+    let a = 1;
+    let b = ) + 2;
+    let c = a;
+    c = ;
+    let d = 1 ) 2;
+    while a { b = 1; }
+}
+"#;
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let Ok(program) = parser.parse::<Program>() else {
+        panic!("expected program");
+    };
+    assert_eq!(parser.errors.len(), 3, "{:?}", parser.errors);
+    let [DeclKind::Fn(main)] = program.decls.as_slice() else {
+        panic!("expected fn, found {:?}", program.decls);
+    };
+    // `d` misses `;` before `)`, it is reported once
+    let stmts = &main.block.stmts;
+    assert_eq!(stmts.len(), 7, "{stmts:?}");
+    assert!(matches!(stmts.last(), Some(StmtKind::While(_))));
+    let broken: Vec<&str> = stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            StmtKind::Error(span) => Some(span.to_lexer_span(&spans).lexeme(source).trim_end()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(broken, ["let b = ) + 2;", "c = ;", ") 2;"]);
+}
+
+#[test]
+fn declarations() {
+    let source = "
+const A = 1;
+pub const B: i32 = 2;
+fn f() {}
+pub fn g(a: i32, b: m::T) -> i32 { a }
+";
+    let (kinds, _) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let Ok(program) = parser.parse::<Program>() else {
+        panic!("expected program");
+    };
+    assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+    let [DeclKind::Const(a), DeclKind::Const(b), DeclKind::Fn(f), DeclKind::Fn(g)] =
+        program.decls.as_slice()
+    else {
+        panic!("expected 2 consts and 2 fns, found {:?}", program.decls);
+    };
+    assert!(matches!(a.vis, Vis::Private) && a.type_path.is_none());
+    assert!(matches!(b.vis, Vis::Public) && b.type_path.is_some());
+    assert!(matches!(f.vis, Vis::Private) && f.params.is_empty() && f.ret.is_none());
+    assert!(matches!(g.vis, Vis::Public) && g.ret.is_some());
+    let lens: Vec<u16> = g.params.iter().map(|param| param.type_path.len).collect();
+    assert_eq!(lens, [0, 1]);
+
+    let cases = [
+        ("const X =", "Unexpected end of file"),
+        ("const X = 1 fn f() {}", "Found `fn`, expected `;`"),
+        (
+            "pub let x = 1;",
+            "Found `let`, expected `function` or `const`",
+        ),
+        ("fn f(a) {}", "Found `)`, expected `:`"),
+        ("fn f(a: i32 {}", "Found `{`, expected `)`"),
+        ("fn f() -> {}", "Found `{`, expected ident"),
+    ];
+    for (source, message) in cases {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        assert!(parser.parse::<Program>().is_ok());
+        let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(messages, [message], "{source}");
+    }
+
+    // Declaration after broken one is parsed
+    let (kinds, _) = lex("fn f(a) {} const B = 1;");
+    let mut parser = Parser::new(&kinds);
+    let program = parser.parse::<Program>().unwrap();
+    assert!(matches!(program.decls.as_slice(), [DeclKind::Const(_)]));
+}
+
+#[test]
+fn loops() {
+    use StmtKind::{For, Return, While};
+
+    let source = "
+fn main() {
+    while a { break; }
+    for i in 0..3 { continue; }
+    return a + 1;
+    return;
+}
+";
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let Ok(program) = parser.parse::<Program>() else {
+        panic!("expected program");
+    };
+    assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+    let [DeclKind::Fn(main)] = program.decls.as_slice() else {
+        panic!("expected fn, found {:?}", program.decls);
+    };
+    let stmts = main.block.stmts.as_slice();
+    let [While(while_stmt), For(for_stmt), Return(value), Return(empty)] = stmts else {
+        panic!("expected loops and returns, found {stmts:?}");
+    };
+    assert!(matches!(while_stmt.condition, ExprKind::Path(_)));
+    assert!(matches!(
+        while_stmt.block.stmts.as_slice(),
+        [StmtKind::Break(_)]
+    ));
+    assert_eq!(spans[for_stmt.binding.as_index()].lexeme(source), "i");
+    assert!(matches!(for_stmt.iter, ExprKind::Range(_)));
+    assert!(matches!(
+        for_stmt.block.stmts.as_slice(),
+        [StmtKind::Continue(_)]
+    ));
+    assert!(matches!(
+        value.stmt.as_deref(),
+        Some(StmtKind::Expr(ExprKind::Binary(_)))
+    ));
+    assert!(empty.stmt.is_none());
+
+    for source in ["fn main() { return 1 }", "fn main() { while a { break } }"] {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        assert!(parser.parse::<Program>().is_ok());
+        let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(messages, ["Found `}`, expected `;`"], "{source}");
+        assert!(parser.errors[0].fix().is_some());
+    }
+
+    let cases = [
+        (
+            "fn main() { return let x = 1; }",
+            "Found `variable`, expected `expression`",
+        ),
+        (
+            "fn main() { return while a { }; }",
+            "Found `while`, expected `expression`, `block` or `if statement`",
+        ),
+        (
+            "fn main() { while let a = 1 { } }",
+            "Found `variable`, expected `expression`",
+        ),
+    ];
+    for (source, message) in cases {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        let _ = parser.parse::<Program>();
+        let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages.first().map(String::as_str),
+            Some(message),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn unclosed() {
+    let cases = [
+        ("fn main() {", "Expected `}`"),
+        ("fn main() { let x = 1;", "Expected `}`"),
+        ("fn main() { if x { 1 }", "Expected `}`"),
+        ("fn main() { x", "Expected `;` or `}`"),
+    ];
+    for (source, message) in cases {
+        let (kinds, _) = lex(source);
+        let mut parser = Parser::new(&kinds);
+        assert!(parser.parse::<Program>().is_ok());
+        let messages: Vec<String> = parser.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages.first().map(String::as_str),
+            Some(message),
+            "{source}"
+        );
+        // Every error points at the end of file
+        let eof = kinds.len() as u32 - 1;
+        assert!(parser
+            .errors
+            .iter()
+            .all(|error| error.span().start.0 == eof));
+    }
+}
+
+#[test]
+fn error_nodes() {
+    let source = "a + )";
+    let (kinds, spans) = lex(source);
+    let mut parser = Parser::new(&kinds);
+    let Ok(ExprKind::Binary(add)) = parser.parse::<ExprKind>() else {
+        panic!("expected binary");
+    };
+    let ExprKind::Error(rhs) = &*add.rhs else {
+        panic!("expected error node, found {:?}", add.rhs);
+    };
+    // `)` is left to enclosing expression
+    assert_eq!(rhs.to_lexer_span(&spans).lexeme(source), "");
+
+    let source = "b(c, +)";
+    let (kinds, spans) = lex(source);
     let mut parser = Parser::new(&kinds);
-    for _ in 0..1 {
-        let _ = parse_with_parser::<StmtKind>(&mut parser, &spans, path, &source);
-        parser.errors.clear();
+    let Ok(ExprKind::Call(call)) = parser.parse::<ExprKind>() else {
+        panic!("expected call");
+    };
+    assert_eq!(parser.errors.len(), 1);
+    let [ExprKind::Path(_), ExprKind::Error(arg)] = call.args.as_slice() else {
+        panic!("expected path and error node, found {:?}", call.args);
+    };
+    assert_eq!(arg.to_lexer_span(&spans).lexeme(source).trim_end(), "+");
+}
+
+#[test]
+fn spans() {
+    // Check that every child lies inside of its parent, returns count of nodes
+    fn check_stmt(parent: Span, stmt: &StmtKind) -> usize {
+        let span = stmt.span();
+        assert!(parent.contains(span), "{stmt:?} is outside of {parent:?}");
+        1 + match stmt {
+            StmtKind::Expr(expr) => check_expr(span, expr),
+            StmtKind::Var(var) => var
+                .stmt
+                .as_deref()
+                .map_or(0, |inner| check_stmt(span, inner)),
+            StmtKind::Assign(assign) => {
+                check_expr(span, &assign.call) + check_stmt(span, &assign.stmt)
+            }
+            StmtKind::Block(block) => check_block(span, block),
+            StmtKind::If(if_stmt) => {
+                let else_stmt = if_stmt.else_stmt.as_deref();
+                check_stmt(span, &if_stmt.condition)
+                    + check_block(span, &if_stmt.block)
+                    + else_stmt.map_or(0, |inner| check_stmt(span, inner))
+            }
+            StmtKind::While(while_stmt) => {
+                check_expr(span, &while_stmt.condition) + check_block(span, &while_stmt.block)
+            }
+            StmtKind::For(for_stmt) => {
+                check_expr(span, &for_stmt.iter) + check_block(span, &for_stmt.block)
+            }
+            StmtKind::Return(return_stmt) => {
+                let inner = return_stmt.stmt.as_deref();
+                inner.map_or(0, |inner| check_stmt(span, inner))
+            }
+            StmtKind::Break(_) | StmtKind::Continue(_) | StmtKind::Error(_) => 0,
+        }
+    }
+
+    fn check_block(parent: Span, block: &BlockStmt) -> usize {
+        assert!(
+            parent.contains(block.span),
+            "{block:?} is outside of {parent:?}"
+        );
+        let stmts = block.stmts.iter();
+        1 + stmts
+            .map(|inner| check_stmt(block.span, inner))
+            .sum::<usize>()
+    }
+
+    fn check_expr(parent: Span, expr: &ExprKind) -> usize {
+        let span = expr.span();
+        assert!(parent.contains(span), "{expr:?} is outside of {parent:?}");
+        1 + match expr {
+            ExprKind::Interpolated(interpolated) => interpolated
+                .parts
+                .iter()
+                .map(|part| match part {
+                    InterpolatedPart::Str(_) => 0,
+                    InterpolatedPart::Expr(inner) => check_expr(span, inner),
+                })
+                .sum(),
+            ExprKind::Unary(unary) => check_expr(span, &unary.expr),
+            ExprKind::Binary(binary) => {
+                check_expr(span, &binary.lhs) + check_expr(span, &binary.rhs)
+            }
+            ExprKind::Call(call) => {
+                let args = call.args.iter().map(|arg| check_expr(span, arg));
+                check_expr(span, &call.callee) + args.sum::<usize>()
+            }
+            ExprKind::Field(field) => check_expr(span, &field.expr),
+            ExprKind::Index(index) => {
+                check_expr(span, &index.expr) + check_expr(span, &index.index)
+            }
+            ExprKind::Range(range) => [&range.start, &range.end]
+                .into_iter()
+                .flatten()
+                .map(|inner| check_expr(span, inner))
+                .sum(),
+            ExprKind::Lit(_) | ExprKind::Path(_) | ExprKind::Error(_) => 0,
+        }
+    }
+
+    let mut sources = lexer::SourceMap::new();
+    let main = sources.load("main.rw").unwrap();
+    let unary = sources.add(
+        "unary.rw",
+        r#"fn f(a: i32) { let b = -!"hp: {-a}".len(); }"#,
+    );
+    for file in [main, unary] {
+        let source = sources.file(file).source();
+        let (kinds, spans) = tokenize(&sources, file);
+        let mut parser = Parser::new(&kinds);
+        let Ok(program) = parser.parse::<Program>() else {
+            panic!("expected program");
+        };
+        assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+
+        let mut count = 0;
+        for decl in &program.decls {
+            let span = decl.span();
+            assert!(program.span.contains(span));
+            count += match decl {
+                DeclKind::Const(var) => check_stmt(span, &StmtKind::Var(var.clone())),
+                DeclKind::Fn(fn_decl) => {
+                    for param in &fn_decl.params {
+                        assert!(span.contains(param.span));
+                        assert!(param.span.contains(param.type_path.span()));
+                    }
+                    check_block(span, &fn_decl.block)
+                }
+            };
+        }
+        assert!(
+            count > 5,
+            "only {count} nodes in {}",
+            sources.file(file).path()
+        );
+
+        // Byte span ends at the last token without whitespace after it
+        let last = program.decls.last().unwrap().span();
+        let lexeme = last.to_lexer_span(&spans).lexeme(source);
+        assert!(
+            lexeme.starts_with("fn ") && lexeme.ends_with('}'),
+            "{lexeme:?}"
+        );
     }
 }
 
-fn tokenize(path: &str, source: &str) -> (Vec<Kind>, Vec<lexer::Span>) {
+/// Kinds and spans of tokens of single file `source`, the last is [Kind::Eof]
+#[cfg(test)]
+fn lex(source: &str) -> (Vec<Kind>, Vec<lexer::Span>) {
+    let mut sources = lexer::SourceMap::new();
+    let file = sources.add("test.rw", source);
+    tokenize(&sources, file)
+}
+
+#[cfg(test)]
+fn tokenize(sources: &lexer::SourceMap, file: lexer::FileId) -> (Vec<Kind>, Vec<lexer::Span>) {
     use crate::lexer::Lexer;
-    let mut lexer = Lexer::new(source);
+    let source = sources.file(file).source();
+    let mut lexer = Lexer::with_file(file, source);
     let mut kinds = Vec::new();
     let mut spans = Vec::new();
     let mut count = 1u32;
@@ -66,32 +652,31 @@ fn tokenize(path: &str, source: &str) -> (Vec<Kind>, Vec<lexer::Span>) {
     (kinds, spans)
 }
 
+#[cfg(test)]
 fn parse_with_parser<P: Parse>(
     parser: &mut Parser,
+    sources: &lexer::SourceMap,
     spans: &[lexer::Span],
-    path: &str,
-    source: &str,
 ) -> Result<P::Parsed, P::Error>
 where
-    P::Parsed: Debug,
-    P::Error: Debug,
+    P::Parsed: std::fmt::Debug,
+    P::Error: std::fmt::Debug,
 {
     let parsed = parser.parse::<P>();
     if parser.errors.is_empty() {
         println!("{:#?}", parsed);
     }
-    print_errors(path, source, spans, &parser.errors);
+    print_errors(sources, spans, &parser.errors);
     parsed
 }
 
-fn print_errors(path: &str, source: &str, spans: &[lexer::Span], errors: &[ParseError]) {
+#[cfg(test)]
+fn print_errors(sources: &lexer::SourceMap, spans: &[lexer::Span], errors: &[ParseError]) {
+    use crate::diagnostic::{Diagnostic, Renderer};
+
+    let renderer = Renderer::new(sources);
     for error in errors.iter() {
-        let span = error.span();
-        let start = spans[span.start.as_index()].start;
-        let end = spans[span.end.as_index()].start;
-        let span = lexer::Span::new(start, end);
-        let location = span.location(source);
-        println!("error: {}", error);
-        println!("   --> {}:{}", path, location);
+        let diagnostic = Diagnostic::from_parse_error(error, spans);
+        println!("{}", renderer.render(&diagnostic));
     }
 }
@@ -1,14 +1,17 @@
 use crate::lexer::{BinaryKind, Kind, TokenId};
 
 use super::{
-    BinaryExpr, ExpectedItem, ExprKind, Item, ItemKind, ItemSequence, Parse, ParseError, Token,
-    TokenStream,
+    BinaryExpr, ExpectedItem, ExprKind, Item, ItemKind, ItemSequence, Parse, ParseError, Span,
+    Token, TokenStream,
 };
 
 #[derive(Debug, Clone)]
 pub struct Parser<'a> {
     pub stream: TokenStream<'a>,
     pub errors: Vec<ParseError>,
+    /// Tokens skipped by error recovery starting at the error that broke
+    /// parsing, other errors inside of them are its cascade
+    pub recovered: Vec<Span>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -31,6 +34,7 @@ impl<'a> Parser<'a> {
         Self {
             stream: TokenStream::new(kinds),
             errors: Vec::new(),
+            recovered: Vec::new(),
         }
     }
 }
@@ -46,6 +50,19 @@ impl<'a> Parser<'a> {
     {
         self.errors.push(error.into());
     }
+
+    /// Record tokens from the last of errors since `error_count` to current one
+    /// as skipped by recovery
+    pub fn push_recovered(&mut self, error_count: usize) {
+        let Some(root) = self.errors[error_count..].last() else {
+            return;
+        };
+        let start = root.span().start;
+        let end = self.stream.current_id();
+        if start.0 <= end.0 {
+            self.recovered.push(Span::new(start, end));
+        }
+    }
 }
 
 impl<'a> Parser<'a> {
@@ -77,6 +94,7 @@ impl<'a> Parser<'a> {
     where
         F: FnMut(&mut Parser<'a>) -> Result<ExprKind, ()>,
     {
+        let start = self.stream.current_id();
         let mut node = f(self)?;
         loop {
             let token = match self.stream.expect_any(operators) {
@@ -89,15 +107,25 @@ impl<'a> Parser<'a> {
                     id: _,
                     kind: Kind::Binary(kind),
                 } => {
-                    let Ok(rhs) = f(self) else {
-                        self.push_error(ExpectedItem::after(
-                            // binary operators work with statements
-                            ItemSequence::StmtWithReturnValue,
-                            Item::from_token(self.stream.second()),
-                        ));
-                        return Ok(node);
+                    let rhs_start = self.stream.current_id();
+                    let rhs = match f(self) {
+                        Ok(rhs) => rhs,
+                        Err(_) => {
+                            self.push_error(ExpectedItem::after(
+                                // binary operators work with statements
+                                ItemSequence::StmtWithReturnValue,
+                                Item::from_token(self.stream.second()),
+                            ));
+                            let end = self.stream.current_id();
+                            let rhs = ExprKind::Error(Span::new(rhs_start, end));
+                            let span = Span::new(start, end);
+                            let node = BinaryExpr::new(span, kind, Box::new(node), Box::new(rhs));
+                            return Ok(ExprKind::Binary(node));
+                        }
                     };
-                    node = ExprKind::Binary(BinaryExpr::new(kind, Box::new(node), Box::new(rhs)));
+                    let span = Span::new(start, self.stream.current_id());
+                    let binary = BinaryExpr::new(span, kind, Box::new(node), Box::new(rhs));
+                    node = ExprKind::Binary(binary);
                 }
                 _ => return Ok(node),
             }
@@ -105,11 +133,36 @@ impl<'a> Parser<'a> {
     }
 }
 
+impl<'a> Parser<'a> {
+    /// Parse expression with `f`, if it fails skipped tokens become [ExprKind::Error]
+    pub fn parse_expr_or_error<F>(&mut self, f: F) -> ExprKind
+    where
+        F: FnOnce(&mut Parser<'a>) -> Result<ExprKind, ()>,
+    {
+        let start = self.stream.current_id();
+        match f(self) {
+            Ok(expr) => expr,
+            Err(_) => ExprKind::Error(Span::new(start, self.stream.current_id())),
+        }
+    }
+}
+
 impl<'a> Parser<'a> {
     pub fn expect(&mut self, kind: Kind) -> Result<TokenId, ()> {
         self.stream.expect(kind)
     }
 
+    /// Like [Parser::expect] but push [ExpectedItem] if kind does not match.
+    pub fn expect_item(&mut self, kind: Kind) -> Result<TokenId, ()> {
+        let token = self.stream.first();
+        if token.kind != kind {
+            let expected = ItemSequence::Single(ItemKind::from_lexer_kind(kind));
+            self.push_error(ExpectedItem::here(expected, Item::from_token(token)));
+            return Err(());
+        }
+        Ok(self.stream.next_id())
+    }
+
     pub fn peek(&mut self) -> Result<Token, ()> {
         let token = self.stream.first();
         if let Kind::Eof = token.kind {
@@ -120,92 +173,6 @@ impl<'a> Parser<'a> {
 }
 
 /*
-impl<'a> Parser<'a> {
-    pub fn parse(mut self) -> (Vec<DeclKind>, Vec<ParseError<'a>>) {
-        let mut declarations = Vec::new();
-        let mut errors = Vec::new();
-        loop {
-            match self.parse_decl() {
-                Ok(decl) => declarations.push(decl),
-                Err(ParseError {
-                    kind: ParseErrorKind::Eof,
-                    ..
-                }) => break,
-                Err(err) => errors.push(err),
-            }
-        }
-        (declarations, errors)
-    }
-
-    pub fn parse_decl(&mut self) -> Result<DeclKind, ParseError> {
-        let current = self.current;
-        let vis = self.parse_visibility()?;
-        let token = self.peek()?;
-        self.current = current;
-        let stmt = match token.kind {
-            Kind![let] | Kind![const] | Kind::Ident => match self.parse_stmt() {
-                Ok(stmt) => {
-                    self.expect(Kind![;])?;
-                    DeclKind::Statement(stmt)
-                }
-                Err(err) => {
-                    loop {
-                        let token = self.next()?;
-                        if matches!(token.kind, Kind![;]) {
-                            break;
-                        }
-                    }
-                    return Err(err);
-                }
-            },
-            Kind![fn] => self.parse_fn(vis)?,
-            _ => {
-                self.skip();
-                return Err(ParseError::unexpected(token));
-            }
-        };
-        Ok(stmt)
-    }
-
-    fn parse_fn(&mut self, vis: Vis) -> Result<DeclKind, ParseError> {
-        self.expect(Kind![fn])?;
-        let name = self.expect(Kind::Ident)?;
-        self.expect(Kind!['('])?;
-        // TODO: parse args
-        self.expect(Kind![')'])?;
-        self.expect(Kind!['{'])?;
-        let mut inner = Vec::new();
-        loop {
-            let Ok(token) = self.peek() else {
-                return Ok(DeclKind::Fn(FnDecl { vis, name, inner }));
-            };
-            if let Kind!['}'] = token.kind {
-                self.skip();
-                break;
-            }
-            match self.parse_stmt() {
-                Ok(stmt) => {
-                    inner.push(stmt);
-                    self.expect(Kind![;])?;
-                }
-                Err(err) => {
-                    self.errors.push(err);
-                    loop {
-                        let Ok(token) = self.peek() else {
-                            return Ok(DeclKind::Fn(FnDecl { vis, name, inner }));
-                        };
-                        if let Kind![;] = token.kind {
-                            break;
-                        }
-                        self.skip();
-                    }
-                }
-            }
-        }
-        Ok(DeclKind::Fn(FnDecl { vis, name, inner }))
-    }
-}
-
 // region: ---- Statement ----
 
 impl<'a> Parser<'a> {
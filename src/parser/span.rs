@@ -1,5 +1,7 @@
 use crate::lexer::{self, TokenId};
 
+/// Range of tokens of one file, its file and bytes are known
+/// from token spans, look [Span::to_lexer_span]
 #[derive(Debug, Clone, Copy)]
 pub struct Span {
     pub start: TokenId,
@@ -16,9 +18,27 @@ impl Span {
         Self { start: id, end: id }
     }
 
+    /// Span of the single token `id`
+    pub fn token(id: TokenId) -> Self {
+        Self::new(id, TokenId(id.0 + 1))
+    }
+
+    /// Check if `other` lies inside of this span, empty spans at the edges included
+    pub fn contains(&self, other: Span) -> bool {
+        self.start.0 <= other.start.0 && other.end.0 <= self.end.0
+    }
+
+    /// Byte span of tokens, `spans` are spans of tokens from the same file.
+    ///
+    /// Ends at the end of the last token, so whitespace and comments after it
+    /// are not included. Empty span is empty byte span before its token.
     pub fn to_lexer_span(&self, spans: &[lexer::Span]) -> lexer::Span {
-        let start = spans[self.start.0 as usize].start;
-        let end = spans[self.end.0 as usize].start;
-        lexer::Span::new(start, end)
+        let start = spans[self.start.as_index()];
+        let end = if self.end.0 > self.start.0 {
+            spans[self.end.as_index() - 1].end
+        } else {
+            start.start
+        };
+        lexer::Span::new(start.file, start.start, end)
     }
 }
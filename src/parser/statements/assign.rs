@@ -1,8 +1,8 @@
 use crate::{
-    lexer::{AssignKind, Kind},
+    lexer::{AssignKind, Kind, OverflowMode},
     parser::{
-        ExpectedItem, ExprKind, ExpressionExt, Item, ItemSequence, Parse, Parser, StatementExt,
-        Token,
+        ExpectedItem, ExprKind, ExpressionExt, Item, ItemSequence, Parse, Parser, Span,
+        StatementExt, Token,
     },
 };
 
@@ -10,8 +10,10 @@ use super::StmtKind;
 
 #[derive(Debug, Clone)]
 pub struct AssignStmt {
+    pub span: Span,
     pub call: Box<ExprKind>,
     pub op: AssignKind,
+    pub overflow: Option<OverflowMode>,
     pub stmt: Box<StmtKind>,
 }
 
@@ -20,10 +22,11 @@ impl Parse for AssignStmt {
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
+        let start = parser.stream.current_id();
         let call = parser.parse_expression()?;
         let token = parser.peek()?;
-        let op = match token.kind {
-            Kind::Assign(kind) => kind,
+        let (op, overflow) = match token.kind {
+            Kind::Assign(kind, overflow) => (kind, overflow),
             _ => {
                 parser.push_error(ExpectedItem::here(
                     ItemSequence::Assign, //
@@ -37,10 +40,12 @@ impl Parse for AssignStmt {
             parser.push_error(stmt_after_assign_expected(assign));
             return Err(());
         };
-        
+
         Ok(Self {
+            span: Span::new(start, parser.stream.current_id()),
             call: Box::new(call),
             op,
+            overflow,
             stmt: Box::new(stmt),
         })
     }
@@ -1,21 +1,27 @@
 use crate::{
     lexer::Kind,
-    parser::{Parse, Parser, StatementsExt},
+    parser::{Parse, Parser, Span, StatementsExt},
 };
 
 use super::StmtKind;
 
 #[derive(Debug, Clone)]
-pub struct BlockStmt(pub Vec<StmtKind>);
+pub struct BlockStmt {
+    pub span: Span,
+    pub stmts: Vec<StmtKind>,
+}
 
 impl Parse for BlockStmt {
     type Parsed = BlockStmt;
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
-        parser.expect(Kind!['{'])?;
+        let start = parser.expect_item(Kind!['{'])?;
         let stmts = parser.parse_statements()?;
-        parser.expect(Kind!['}'])?;
-        Ok(Self(stmts))
+        parser.expect_item(Kind!['}'])?;
+        Ok(Self {
+            span: Span::new(start, parser.stream.current_id()),
+            stmts,
+        })
     }
 }
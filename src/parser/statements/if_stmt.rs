@@ -1,12 +1,13 @@
 use crate::{
     lexer::Kind,
-    parser::{BlockExt, Parse, ParseError, Parser, StatementExt},
+    parser::{BlockExt, Parse, ParseError, Parser, Span, StatementExt},
 };
 
 use super::{BlockStmt, StmtKind};
 
 #[derive(Debug, Clone)]
 pub struct IfStmt {
+    pub span: Span,
     pub condition: Box<StmtKind>,
     pub block: BlockStmt,
     pub else_stmt: Option<Box<StmtKind>>,
@@ -17,7 +18,7 @@ impl Parse for IfStmt {
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
-        parser.expect(Kind![if])?;
+        let start = parser.expect(Kind![if])?;
         let Ok(condition) = parser.parse_statement() else {
             return Err(());
         };
@@ -31,6 +32,7 @@ impl Parse for IfStmt {
         };
 
         Ok(Self {
+            span: Span::new(start, parser.stream.current_id()),
             condition: Box::new(condition),
             block,
             else_stmt,
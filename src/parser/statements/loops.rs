@@ -0,0 +1,110 @@
+use crate::{
+    lexer::{Kind, TokenId},
+    parser::{
+        BlockExt, ExpectedItem, ExprKind, ExpressionExt, IfExt, Item, ItemSequence, Parse, Parser,
+        Span,
+    },
+};
+
+use super::{BlockStmt, StmtKind};
+
+/// `while condition { ... }`
+#[derive(Debug, Clone)]
+pub struct WhileStmt {
+    pub span: Span,
+    pub condition: ExprKind,
+    pub block: BlockStmt,
+}
+
+impl Parse for WhileStmt {
+    type Parsed = WhileStmt;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
+        let start = parser.expect(Kind![while])?;
+        let condition = parser.parse_expr_or_error(Parser::parse_expression);
+        let block = parser.parse_block()?;
+
+        Ok(Self {
+            span: Span::new(start, parser.stream.current_id()),
+            condition,
+            block,
+        })
+    }
+}
+
+/// `for binding in iter { ... }`
+#[derive(Debug, Clone)]
+pub struct ForStmt {
+    pub span: Span,
+    pub binding: TokenId,
+    pub iter: ExprKind,
+    pub block: BlockStmt,
+}
+
+impl Parse for ForStmt {
+    type Parsed = ForStmt;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
+        let start = parser.expect(Kind![for])?;
+        let binding = parser.expect_item(Kind::Ident)?;
+        parser.expect_item(Kind![in])?;
+        let iter = parser.parse_expression()?;
+        let block = parser.parse_block()?;
+
+        Ok(Self {
+            span: Span::new(start, parser.stream.current_id()),
+            binding,
+            iter,
+            block,
+        })
+    }
+}
+
+/// `return` or `return value`
+#[derive(Debug, Clone)]
+pub struct ReturnStmt {
+    pub span: Span,
+    pub keyword: TokenId,
+    pub stmt: Option<Box<StmtKind>>,
+}
+
+impl Parse for ReturnStmt {
+    type Parsed = ReturnStmt;
+    type Error = ();
+
+    fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
+        let keyword = parser.expect(Kind![return])?;
+        let token = parser.stream.first();
+        let stmt = match token.kind {
+            Kind![;] | Kind!['}'] | Kind::Eof => None,
+            _ => Some(Box::new(parse_return_value(parser)?)),
+        };
+
+        Ok(Self {
+            span: Span::new(keyword, parser.stream.current_id()),
+            keyword,
+            stmt,
+        })
+    }
+}
+
+/// Value of `return`: expression, block or `if`
+fn parse_return_value(parser: &mut Parser<'_>) -> Result<StmtKind, ()> {
+    let token = parser.stream.first();
+    match token.kind {
+        Kind!['{'] => Ok(StmtKind::Block(parser.parse_block()?)),
+        Kind![if] => Ok(StmtKind::If(parser.parse_if()?)),
+        _ => {
+            let error_count = parser.errors.len();
+            let expr = parser.parse_expression();
+            if expr.is_err() && parser.errors.len() == error_count {
+                // Statements like `while` have no value
+                let expected = ItemSequence::StmtWithReturnValue;
+                parser.push_error(ExpectedItem::here(expected, Item::from_token(token)));
+            }
+            Ok(StmtKind::Expr(expr?))
+        }
+    }
+}
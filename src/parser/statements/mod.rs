@@ -1,7 +1,10 @@
-use crate::lexer::Kind;
+use crate::lexer::{Kind, TokenId};
 
-use super::{AssignExt, BlockExt, ExpressionExt, IfExt, VariableExt};
-use super::{ExprKind, Parse, Parser};
+use super::{
+    AssignExt, BlockExt, DocsExt, ExpressionExt, ForExt, IfExt, ReturnExt, StatementExt,
+    VariableExt, WhileExt,
+};
+use super::{ExpectedItem, ExprKind, Item, ItemKind, ItemSequence, Parse, Parser, Span};
 
 mod utils;
 pub use utils::*;
@@ -18,6 +21,9 @@ pub use block::*;
 mod if_stmt;
 pub use if_stmt::*;
 
+mod loops;
+pub use loops::*;
+
 mod stmts;
 pub use stmts::*;
 
@@ -28,6 +34,13 @@ pub enum StmtKind {
     Assign(AssignStmt),
     Block(BlockStmt),
     If(IfStmt),
+    While(WhileStmt),
+    For(ForStmt),
+    Break(TokenId),
+    Continue(TokenId),
+    Return(ReturnStmt),
+    /// Tokens of statement which failed to parse, its error is already reported
+    Error(Span),
 }
 
 #[derive(Debug, Clone)]
@@ -37,9 +50,29 @@ pub enum ParseStmtError {
     Assign,
     Block,
     If,
+    While,
+    For,
+    Return,
     Eof,
 }
 
+impl StmtKind {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Expr(expr) => expr.span(),
+            Self::Var(var) => var.span,
+            Self::Assign(assign) => assign.span,
+            Self::Block(block) => block.span,
+            Self::If(if_stmt) => if_stmt.span,
+            Self::While(while_stmt) => while_stmt.span,
+            Self::For(for_stmt) => for_stmt.span,
+            Self::Break(id) | Self::Continue(id) => Span::token(*id),
+            Self::Return(return_stmt) => return_stmt.span,
+            Self::Error(span) => *span,
+        }
+    }
+}
+
 impl From<()> for ParseStmtError {
     fn from(_: ()) -> Self {
         Self::Eof
@@ -52,6 +85,21 @@ impl Parse for StmtKind {
 
     fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ParseStmtError> {
         let token = parser.peek()?;
+        if matches!(token.kind, Kind::Docs) {
+            let token = parser.with_frame(|parser| {
+                let _ = parser.parse_docs();
+                parser.stream.maybe(Kind![pub]);
+                parser.peek()
+            })?;
+            if !matches!(token.kind, Kind![const] | Kind![let] | Kind![mut]) {
+                // Only variables have docs, parse statement without them
+                let expected = ItemSequence::Single(ItemKind::VarStmt);
+                parser.push_error(ExpectedItem::after(expected, Item::from_token(token)));
+                let _ = parser.parse_docs();
+                return parser.parse_statement();
+            }
+        }
+
         if matches!(token.kind, Kind!['{']) {
             let block = match parser.parse_block() {
                 Ok(block) => block,
@@ -68,7 +116,32 @@ impl Parse for StmtKind {
             return Ok(Self::If(if_stmt));
         }
 
+        match token.kind {
+            Kind![while] => {
+                return match parser.parse_while() {
+                    Ok(while_stmt) => Ok(Self::While(while_stmt)),
+                    Err(_) => Err(ParseStmtError::While),
+                };
+            }
+            Kind![for] => {
+                return match parser.parse_for() {
+                    Ok(for_stmt) => Ok(Self::For(for_stmt)),
+                    Err(_) => Err(ParseStmtError::For),
+                };
+            }
+            Kind![break] => return Ok(Self::Break(parser.stream.next_id())),
+            Kind![continue] => return Ok(Self::Continue(parser.stream.next_id())),
+            Kind![return] => {
+                return match parser.parse_return() {
+                    Ok(return_stmt) => Ok(Self::Return(return_stmt)),
+                    Err(_) => Err(ParseStmtError::Return),
+                };
+            }
+            _ => (),
+        }
+
         let token = parser.with_frame(|parser| {
+            let _ = parser.parse_docs();
             parser.stream.maybe(Kind![pub]);
             parser.peek()
         })?;
@@ -82,9 +155,15 @@ impl Parse for StmtKind {
 
         let frame = parser.frame();
         let expr = parser.parse_expression()?;
-        let token = parser.peek()?;
+        let token = parser.stream.first();
+        if let Kind::Eof = token.kind {
+            // File ends inside of block
+            let expected = ItemSequence::StmtEnd;
+            parser.push_error(ExpectedItem::here(expected, Item::from_token(token)));
+            return Ok(Self::Expr(expr));
+        }
 
-        if matches!(token.kind, Kind::Assign(_)) {
+        if matches!(token.kind, Kind::Assign(..)) {
             parser.set_frame(frame);
             let assign = match parser.parse_assign() {
                 Ok(assign) => assign,
@@ -1,9 +1,12 @@
 use crate::{
     lexer::Kind,
-    parser::{Item, ItemKind, Parse, ParseError, Parser, StatementExt, Token},
+    parser::{
+        ExpectedItem, Item, ItemKind, ItemSequence, Parse, ParseError, Parser, Span, StatementExt,
+        TokenFix,
+    },
 };
 
-use super::{ParseStmtError, StmtKind};
+use super::StmtKind;
 
 impl Parse for Vec<StmtKind> {
     type Parsed = Self;
@@ -12,11 +15,32 @@ impl Parse for Vec<StmtKind> {
     fn parse(parser: &mut Parser<'_>) -> Result<Self::Parsed, ()> {
         let mut stmts = Vec::new();
         loop {
-            match parser.parse_statement() {
+            let start = parser.stream.first();
+            if matches!(start.kind, Kind!['}'] | Kind::Eof) {
+                break;
+            }
+            let error_count = parser.errors.len();
+            let stmt = parser.parse_statement();
+            if stmt.is_err() && parser.errors.len() == error_count {
+                let found = Item::from_token(parser.stream.first());
+                parser.push_error(ParseError::Unexpected(found));
+            }
+            // Error at the start of statement is cascade of previous one, like missing `;`
+            if error_count > 0 && parser.errors.len() > error_count {
+                let previous = parser.errors[error_count - 1].span().start;
+                if parser.errors[error_count].span().start == previous {
+                    parser.errors.remove(error_count);
+                }
+            }
+            match stmt {
                 Ok(stmt) => {
                     match stmt {
-                        StmtKind::Assign(_) | StmtKind::Var(_) => {
-                            let _ = parser.expect(Kind![;]);
+                        StmtKind::Assign(_)
+                        | StmtKind::Var(_)
+                        | StmtKind::Break(_)
+                        | StmtKind::Continue(_)
+                        | StmtKind::Return(_) => {
+                            expect_semi(parser);
                             let token = parser.stream.first();
                             if let Kind!['}'] = token.kind {
                                 stmts.push(stmt);
@@ -25,17 +49,81 @@ impl Parse for Vec<StmtKind> {
                         }
                         StmtKind::Expr(_) => {
                             let token = parser.stream.first();
-                            if !matches!(token.kind, Kind!['}']) {
-                                let _ = parser.expect(Kind![;]);
+                            // Missing end at `Eof` is reported by statement
+                            if !matches!(token.kind, Kind!['}'] | Kind::Eof) {
+                                expect_semi(parser);
                             }
                         }
-                        StmtKind::Block(_) | StmtKind::If(_) => (),
+                        StmtKind::Block(_)
+                        | StmtKind::If(_)
+                        | StmtKind::While(_)
+                        | StmtKind::For(_)
+                        | StmtKind::Error(_) => (),
                     }
                     stmts.push(stmt);
                 }
-                Err(err) => break,
+                Err(_) => {
+                    // The first error is the root, the rest are its cascade
+                    parser.errors.truncate(error_count + 1);
+                    if parser.stream.current_id() == start.id {
+                        parser.stream.skip();
+                    }
+                    synchronize(parser);
+                    parser.push_recovered(error_count);
+                    let end = parser.stream.current_id();
+                    stmts.push(StmtKind::Error(Span::new(start.id, end)));
+                }
             }
         }
         Ok(stmts)
     }
 }
+
+/// Skip tokens of broken statement until `;` (skipped too),
+/// closing `}` of block or keyword starting next statement
+fn synchronize(parser: &mut Parser<'_>) {
+    let mut depth = 0u32;
+    loop {
+        match parser.stream.first().kind {
+            Kind::Eof => return,
+            Kind!['{'] => depth += 1,
+            Kind!['}'] if depth == 0 => return,
+            Kind!['}'] => depth -= 1,
+            Kind![;] if depth == 0 => {
+                parser.stream.skip();
+                return;
+            }
+            Kind![let]
+            | Kind![const]
+            | Kind![pub]
+            | Kind![if]
+            | Kind![while]
+            | Kind![for]
+            | Kind![break]
+            | Kind![continue]
+            | Kind![return]
+                if depth == 0 =>
+            {
+                return
+            }
+            _ => (),
+        }
+        parser.stream.skip();
+    }
+}
+
+/// Expect `;` after statement, if it is missing suggest to add it
+fn expect_semi(parser: &mut Parser<'_>) {
+    if parser.expect(Kind![;]).is_ok() {
+        return;
+    }
+    let token = parser.stream.first();
+    let mut error = ExpectedItem::here(
+        ItemSequence::Single(ItemKind::Semi),
+        Item::from_token(token),
+    );
+    if let Some(last) = parser.stream.previous_id() {
+        error = error.with_fix(TokenFix::insert_after(last, ";"));
+    }
+    parser.push_error(error);
+}
@@ -1,8 +1,9 @@
 use crate::{
+    diagnostic::Applicability,
     lexer::{AssignKind, Kind, TokenId},
     parser::{
-        ExpectedItem, Item, ItemKind, ItemSequence, Parse, ParseError, Parser, Span, StatementExt,
-        SyntaxError, TypePathExpr, TypePathExt,
+        Docs, DocsExt, ExpectedItem, Item, ItemKind, ItemSequence, Parse, ParseError, Parser, Span,
+        StatementExt, SyntaxError, TokenFix, TypePathExpr, TypePathExt,
     },
 };
 
@@ -11,6 +12,7 @@ use super::{Mut, StmtKind, Vis};
 #[derive(Debug, Clone)]
 pub struct VarStmt {
     pub span: Span,
+    pub docs: Docs,
     pub vis: Vis,
     pub kind: VarKind,
     pub mutability: Mut,
@@ -25,6 +27,7 @@ impl Parse for VarStmt {
     type Error = ();
 
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ()> {
+        let docs = parser.parse_docs()?;
         let mut start = None;
         let vis = match parser.stream.maybe(Kind![pub]) {
             Some(id) => {
@@ -71,15 +74,18 @@ impl Parse for VarStmt {
         let token = parser.stream.first();
         match token.kind {
             Kind![=] => (),
-            Kind::Assign(_) => {
+            Kind::Assign(..) => {
                 let eq = ItemKind::Assign(Some(AssignKind::Eq));
                 let expected = ItemSequence::Single(eq);
                 let found = Item::from_token(token);
-                parser.push_error(ExpectedItem::here(expected, found));
+                // `let hp += 1` is likely `let hp = 1`
+                let fix = TokenFix::replace(token.id, "=", Applicability::MaybeIncorrect);
+                parser.push_error(ExpectedItem::here(expected, found).with_fix(fix));
             }
             _ => {
                 return Ok(Self {
                     span: Span::new(start, token.id),
+                    docs,
                     vis,
                     mutability,
                     kind,
@@ -103,6 +109,7 @@ impl Parse for VarStmt {
 
         Ok(Self {
             span: Span::new(start, end),
+            docs,
             vis,
             mutability,
             kind,
@@ -139,6 +139,11 @@ impl<'a> TokenStream<'a> {
     pub fn current_id(&self) -> TokenId {
         self.current
     }
+
+    /// Id of the last skipped token, the first token has no previous
+    pub fn previous_id(&self) -> Option<TokenId> {
+        self.current.0.checked_sub(1).map(TokenId)
+    }
 }
 
 impl<'a> TokenStream<'a> {